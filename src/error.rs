@@ -7,12 +7,22 @@ use thiserror::Error;
 pub type Result<T, E = PathError> = std::result::Result<T, E>;
 
 /// Represents all possible errors that can occur within the path processing modules.
+///
+/// Some variants only exist with an optional feature enabled, so the enum is
+/// non-exhaustive: matches outside this crate need a wildcard arm.
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum PathError {
     /// An error that occurred while parsing an SVG path data string.
-    /// This variant automatically converts from `lyon::extra::parser::ParseError`.
-    #[error("Failed to parse SVG path data: {0}")]
-    Parse(#[from] ParseError),
+    ///
+    /// `position` is the offset, in characters, of the token that failed to parse, and
+    /// `near` is that token as it appears in the input (empty at the end of the input).
+    #[error("Failed to parse SVG path data at position {position} near {near:?}: {source}")]
+    Parse {
+        source: ParseError,
+        position: usize,
+        near: String,
+    },
 
     /// An error indicating that fitting a curve to a set of points failed.
     #[error("Failed to fit a curve to the points")]
@@ -22,8 +32,64 @@ pub enum PathError {
     #[error("Failed to clean the path")]
     CleanPath,
 
+    /// An error indicating that an offset leaves no geometry behind, such as when a shape is
+    /// offset inward by more than its inradius. Unlike [`PathError::CleanPath`], this is a
+    /// legitimate outcome of a distance that is too large, not a failure of the algorithm.
+    #[error("The offset collapses the path to nothing")]
+    EmptyResult,
+
+    /// An error indicating that a per-vertex scalar channel does not have one value for each
+    /// vertex of the path it is attached to.
+    #[error("Expected {expected} scalars, one per vertex of the path, but got {found}")]
+    ScalarCount { expected: usize, found: usize },
+
+    /// An error indicating that the input cannot be processed as given.
+    /// The message describes the problem and how to fix it.
+    #[error("Unsupported input: {0}")]
+    Unsupported(String),
+
+    /// An error that occurred while parsing an SVG document with `usvg`.
+    #[cfg(feature = "usvg")]
+    #[error("Failed to parse SVG document: {0}")]
+    Document(#[from] usvg::Error),
+
     /// An I/O error occurred.
     /// This is useful for operations that might read path data from files.
     #[error("I/O error: {0}")]
     Io(#[from] std::io::Error),
 }
+
+impl PathError {
+    /// Wraps a `lyon` parse error, locating it in the `input` that was parsed.
+    ///
+    /// `lyon` reports errors by line and column; they are turned into a character offset
+    /// into the whole input, along with the offending token.
+    pub(crate) fn parse(input: &str, source: ParseError) -> Self {
+        let (line, column) = match source {
+            ParseError::Number { line, column, .. }
+            | ParseError::Flag { line, column, .. }
+            | ParseError::Command { line, column, .. }
+            | ParseError::MissingMoveTo { line, column, .. } => (line, column),
+            _ => (0, 0),
+        };
+
+        let line_start: usize = input
+            .split('\n')
+            .take(line.max(0) as usize)
+            .map(|line| line.chars().count() + 1)
+            .sum();
+        let position = line_start + column.max(0) as usize;
+        let near = input
+            .chars()
+            .skip(position)
+            .take_while(|c| !c.is_whitespace() && *c != ',')
+            .take(16)
+            .collect();
+
+        PathError::Parse {
+            source,
+            position,
+            near,
+        }
+    }
+}
@@ -25,17 +25,23 @@
 //! ```rust
 //! use path_offset::offset::Offset;
 //! use path_offset::path::Path;
+//! use path_offset::{Backend, offset};
 //! use std::str::FromStr;
 //!
 //! let path = Path::from_str("M10,10 L20,10 L20,20 L10,20 Z").unwrap();
 //!
-//! // Use one of the available offsetters
+//! // Pick a backend in one call
+//! let offset_path = offset(&path, 1.0, Backend::CavalierContours).unwrap();
+//! println!("Offset path: {}", offset_path);
+//!
+//! // Or use one of the available offsetters directly, to configure it
 //! let offsetter = path_offset::offset::cavalier_contours::CavalierContours::new(1.0);
 //! let offset_path = offsetter.offset_path(&path).unwrap();
-//!
 //! println!("Offset path: {}", offset_path);
 //! ```
 
 pub mod error;
 pub mod offset;
 pub mod path;
+
+pub use offset::{Backend, offset};
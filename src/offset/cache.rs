@@ -0,0 +1,130 @@
+//! Memoizes offset results for repeated identical requests.
+//!
+//! Interactive editors often offset the same path by the same distance many times, for
+//! example on every redraw. [`OffsetCache`] remembers the most recent results so that such
+//! calls return immediately instead of running the backend again.
+
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use crate::{error::Result, offset::Backend, path::Path};
+
+/// A least-recently-used cache of offset results.
+///
+/// Entries are keyed by the exact geometry of the source path (see the `Hash` implementation
+/// of [`Path`]), the distance, and the backend. Once `capacity` results are stored, the
+/// least recently used one is evicted to make room for a new one.
+#[derive(Debug, Clone)]
+pub struct OffsetCache {
+    capacity: usize,
+    /// The cached entries, from least to most recently used.
+    entries: Vec<CacheEntry>,
+    hits: usize,
+    misses: usize,
+}
+
+#[derive(Debug, Clone)]
+struct CacheEntry {
+    hash: u64,
+    source: Path,
+    distance: f64,
+    backend: Backend,
+    result: Path,
+}
+
+impl OffsetCache {
+    /// Creates an empty cache that holds at most `capacity` results.
+    pub fn new(capacity: usize) -> Self {
+        OffsetCache {
+            capacity,
+            entries: Vec::with_capacity(capacity),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Returns the cached offset of `path`, computing and caching it if it is not present.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - A reference to the `Path` to be offset.
+    /// * `distance` - The distance by which to offset the path.
+    /// * `backend` - The offsetting implementation to use.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the offset `Path`, or the error raised while computing it.
+    /// Errors are not cached.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::offset::{Backend, cache::OffsetCache};
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// let path = Path::from_str("M0,0 L10,0 L10,10 L0,10 Z").unwrap();
+    /// let mut cache = OffsetCache::new(8);
+    ///
+    /// let first = cache.get_or_compute(&path, 1.0, Backend::FloCurves).unwrap();
+    /// let second = cache.get_or_compute(&path, 1.0, Backend::FloCurves).unwrap();
+    ///
+    /// // The second call is served from the cache without offsetting again.
+    /// assert_eq!((cache.misses(), cache.hits()), (1, 1));
+    /// assert_eq!(first.to_string(), second.to_string());
+    ///
+    /// // A different distance is a different entry.
+    /// cache.get_or_compute(&path, 2.0, Backend::FloCurves).unwrap();
+    /// assert_eq!((cache.misses(), cache.hits()), (2, 1));
+    /// ```
+    pub fn get_or_compute(&mut self, path: &Path, distance: f64, backend: Backend) -> Result<Path> {
+        let mut hasher = DefaultHasher::new();
+        path.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        let position = self.entries.iter().position(|entry| {
+            entry.hash == hash
+                && entry.distance.to_bits() == distance.to_bits()
+                && entry.backend == backend
+                && entry.source.identical_to(path)
+        });
+        if let Some(position) = position {
+            self.hits += 1;
+            // Move the entry to the most recently used end.
+            let entry = self.entries.remove(position);
+            let result = entry.result.clone();
+            self.entries.push(entry);
+            return Ok(result);
+        }
+
+        self.misses += 1;
+        let result = backend.offset_path(path, distance)?;
+        if self.capacity > 0 {
+            if self.entries.len() == self.capacity {
+                self.entries.remove(0);
+            }
+            self.entries.push(CacheEntry {
+                hash,
+                source: path.clone(),
+                distance,
+                backend,
+                result: result.clone(),
+            });
+        }
+        Ok(result)
+    }
+
+    /// Returns the number of calls that were served from the cache.
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// Returns the number of calls that had to compute their result.
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+
+    /// Removes every cached result.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
@@ -1 +1,347 @@
+//! Implements path offsetting using the `cavalier_contours` library.
+//!
+//! This module provides the `CavalierContours` struct, which uses the parallel offset
+//! algorithm of `cavalier_contours` to offset paths made of lines and circular arcs.
 
+use cavalier_contours::{
+    core::math::Vector2,
+    polyline::{
+        PlineCreation, PlineSource, PlineSourceMut, PlineVertex, Polyline,
+        seg_arc_radius_and_center,
+    },
+};
+
+use crate::{
+    error::{PathError, Result},
+    offset::{CapStyle, JoinStyle, Offset},
+    path::{Path, conversions::cavalier_contours::DEFAULT_ARC_SEGMENTS_PER_QUADRANT},
+};
+
+/// A path offsetter that uses the `cavalier_contours` library.
+///
+/// Each subpath is converted into a `cavalier_contours` polyline (see
+/// [`conversions::cavalier_contours`](crate::path::conversions::cavalier_contours)) and
+/// offset on its own. Offsetting can split a polyline into several contours, for example
+/// when shrinking a dumbbell shape past the width of its handle, and every one of them is
+/// kept as a subpath of the result.
+///
+/// As with [`FloCurvesOffset`](super::flo_curves::FloCurvesOffset), a positive distance
+/// grows subpaths with a positive signed area and shrinks the others.
+///
+/// # Example
+///
+/// ```
+/// use path_offset::offset::{Offset, cavalier_contours::CavalierContours};
+/// use path_offset::path::Path;
+/// use std::str::FromStr;
+///
+/// // Two 10x10 squares joined by a 10x2 handle.
+/// let dumbbell = Path::from_str(
+///     "M0,0 L10,0 L10,4 L20,4 L20,0 L30,0 L30,10 L20,10 L20,6 L10,6 L10,10 L0,10 Z",
+/// )
+/// .unwrap();
+///
+/// // Shrinking by more than half the handle's width splits the shape in two.
+/// let islands = CavalierContours::new(-2.0).offset_path(&dumbbell).unwrap();
+/// assert_eq!(islands.subpath_count(), 2);
+/// assert!(islands.iter().all(|island| island.is_closed()));
+/// ```
+pub struct CavalierContours {
+    offset_distance: f64,
+    join_style: JoinStyle,
+    cap_style: Option<CapStyle>,
+    arc_segments_per_quadrant: usize,
+}
+
+impl CavalierContours {
+    /// Creates a new `CavalierContours` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset_distance` - The distance by which to offset paths.
+    pub fn new(offset_distance: f64) -> Self {
+        CavalierContours {
+            offset_distance,
+            join_style: JoinStyle::Round,
+            cap_style: None,
+            arc_segments_per_quadrant: DEFAULT_ARC_SEGMENTS_PER_QUADRANT,
+        }
+    }
+
+    /// Sets how the offset segments on either side of each convex corner are joined.
+    ///
+    /// `cavalier_contours` joins convex corners with arcs around the source vertex, so
+    /// [`JoinStyle::Round`] is the default. With the other styles, those arcs are replaced
+    /// by a miter or a bevel after offsetting.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::offset::{JoinStyle, Offset, cavalier_contours::CavalierContours};
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// let path = Path::from_str("M0,0 L10,0 L10,10 L0,10 Z").unwrap();
+    /// let miter = |limit: f64| {
+    ///     let offset_path = CavalierContours::new(1.0)
+    ///         .with_join_style(JoinStyle::Miter { limit })
+    ///         .offset_path(&path)
+    ///         .unwrap();
+    ///     offset_path.report(0.01).length
+    /// };
+    ///
+    /// assert!((miter(4.0) - 48.0).abs() < 0.05);
+    /// // A miter limit below the ratio of a right angle (about 1.41) bevels the corners.
+    /// assert!((miter(1.2) - (40.0 + 4.0 * 2f64.sqrt())).abs() < 0.05);
+    /// ```
+    pub fn with_join_style(mut self, join_style: JoinStyle) -> Self {
+        self.join_style = join_style;
+        self
+    }
+
+    /// Sets how open subpaths are outlined.
+    ///
+    /// By default an open subpath is offset to one side only, into another open subpath.
+    /// With a cap style, it is instead outlined on both sides at the offset distance, as if
+    /// stroked with a pen twice as wide, and the two sides are connected around its ends
+    /// with caps. The result is a closed outline whatever the sign of the distance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::offset::{CapStyle, Offset, cavalier_contours::CavalierContours};
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// let line = Path::from_str("M0,0 L10,0").unwrap();
+    /// let outline = |cap_style: CapStyle| {
+    ///     CavalierContours::new(2.0)
+    ///         .with_cap_style(cap_style)
+    ///         .offset_path(&line)
+    ///         .unwrap()
+    /// };
+    ///
+    /// for cap_style in [CapStyle::Butt, CapStyle::Round, CapStyle::Square] {
+    ///     let outline = outline(cap_style);
+    ///     assert_eq!(outline.subpath_count(), 1);
+    ///     assert!(outline.is_closed());
+    /// }
+    ///
+    /// // Square caps extend the outline past both ends of the line.
+    /// let bounds = |cap_style| outline(cap_style).report(0.01).bounds.unwrap();
+    /// assert_eq!(bounds(CapStyle::Butt).0.0, 0.0);
+    /// assert_eq!(bounds(CapStyle::Round).0.0, -2.0);
+    /// assert_eq!(bounds(CapStyle::Square).0.0, -2.0);
+    /// assert_eq!(bounds(CapStyle::Square).1.0, 12.0);
+    /// ```
+    pub fn with_cap_style(mut self, cap_style: CapStyle) -> Self {
+        self.cap_style = Some(cap_style);
+        self
+    }
+
+    /// Sets how many cubic curves approximate each quarter turn of the arcs in the result.
+    ///
+    /// `cavalier_contours` offsets into exact circular arcs, around convex corners and along
+    /// curved input, which have to be approximated by cubic curves in the returned path (see
+    /// [`Path::from_polyline`] for how closely they follow the circle). The default is `1`. A
+    /// value of `0` is treated as `1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::offset::{Offset, cavalier_contours::CavalierContours};
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// // Growing a square by 10 rounds each corner with a quarter circle of radius 10.
+    /// let square = Path::from_str("M0,0 L10,0 L10,10 L0,10 Z").unwrap();
+    /// let grown = |arc_segments_per_quadrant: usize| {
+    ///     CavalierContours::new(10.0)
+    ///         .with_arc_segments_per_quadrant(arc_segments_per_quadrant)
+    ///         .offset_path(&square)
+    ///         .unwrap()
+    /// };
+    /// // The largest distance of the corners from their circles.
+    /// let deviation = |path: &Path| {
+    ///     path.to_polygon_rings(0.00001)
+    ///         .iter()
+    ///         .flatten()
+    ///         .map(|&[x, y]| {
+    ///             let corner = (x.clamp(0.0, 10.0), y.clamp(0.0, 10.0));
+    ///             ((x - corner.0).hypot(y - corner.1) - 10.0).abs()
+    ///         })
+    ///         .fold(0.0, f64::max)
+    /// };
+    ///
+    /// // Four edges, and four corners of one or four curves.
+    /// let (coarse, fine) = (grown(1), grown(4));
+    /// assert_eq!(coarse.segment_count(), 8);
+    /// assert_eq!(fine.segment_count(), 20);
+    /// assert!(deviation(&coarse) > 0.002);
+    /// assert!(deviation(&fine) < 0.0001);
+    /// ```
+    pub fn with_arc_segments_per_quadrant(mut self, arc_segments_per_quadrant: usize) -> Self {
+        self.arc_segments_per_quadrant = arc_segments_per_quadrant;
+        self
+    }
+
+    /// Outlines both sides of an open polyline, connected by caps around its ends.
+    fn stroke(&self, source: &Polyline<f64>, cap_style: CapStyle) -> Vec<Polyline<f64>> {
+        let count = source.vertex_count();
+        if count < 2 {
+            return Vec::new();
+        }
+
+        // A closed polyline that runs along the source and back again. Its offset goes
+        // around both sides, and the turns at either end become round joins.
+        let mut there_and_back = Polyline::with_capacity(2 * count - 2, true);
+        for index in 0..count - 1 {
+            there_and_back.add_vertex(source.at(index));
+        }
+        for index in (1..count).rev() {
+            let vertex = source.at(index);
+            there_and_back.add(vertex.x, vertex.y, -source.at(index - 1).bulge);
+        }
+
+        let ends = [source.at(0).pos(), source.at(count - 1).pos()];
+        let corners: Vec<Vector2<f64>> =
+            (1..count - 1).map(|index| source.at(index).pos()).collect();
+        there_and_back
+            .parallel_offset(-self.offset_distance.abs())
+            .iter()
+            .map(|outline| {
+                let capped = self.apply_cap_style(outline, &ends, cap_style);
+                match self.join_style {
+                    JoinStyle::Round => capped,
+                    _ => self.apply_join_style(&capped, &corners),
+                }
+            })
+            .collect()
+    }
+
+    /// Replaces the round caps of a stroke outline according to the cap style.
+    ///
+    /// A round cap is a half circle of radius `offset_distance` centered on one of the
+    /// `ends` of the stroked polyline.
+    fn apply_cap_style(
+        &self,
+        outline: &Polyline<f64>,
+        ends: &[Vector2<f64>],
+        cap_style: CapStyle,
+    ) -> Polyline<f64> {
+        if cap_style == CapStyle::Round {
+            return outline.clone();
+        }
+
+        let mut capped = Polyline::with_capacity(outline.vertex_count(), true);
+        for (index, vertex) in outline.iter_vertexes().enumerate() {
+            let next = outline.at(outline.next_wrapping_index(index));
+            let is_cap = (vertex.bulge.abs() - 1.0).abs() <= 1e-9
+                && ends.iter().any(|end| {
+                    let (_, center) = seg_arc_radius_and_center(vertex, next);
+                    end.fuzzy_eq_eps(center, 1e-6)
+                });
+            if !is_cap {
+                capped.add_vertex(vertex);
+                continue;
+            }
+
+            capped.add(vertex.x, vertex.y, 0.0);
+            if cap_style == CapStyle::Square {
+                // The cap bulges to the right of its chord when the arc is counter-clockwise.
+                let chord = (next.pos() - vertex.pos()).normalize();
+                let outward = Vector2::new(chord.y, -chord.x).scale(vertex.bulge.signum());
+                let extension = outward.scale(self.offset_distance.abs());
+                for corner in [vertex.pos() + extension, next.pos() + extension] {
+                    capped.add_vertex(PlineVertex::new(corner.x, corner.y, 0.0));
+                }
+            }
+        }
+
+        // Square caps are left in line with the sides of the outline.
+        capped.remove_redundant(1e-9).unwrap_or(capped)
+    }
+
+    /// Replaces the round joins of an offset polyline according to the join style.
+    ///
+    /// A round join is an arc of radius `offset_distance` centered on one of the `corners`
+    /// of the source polyline.
+    fn apply_join_style(&self, offset: &Polyline<f64>, corners: &[Vector2<f64>]) -> Polyline<f64> {
+        let distance = self.offset_distance.abs();
+        let mut joined = Polyline::with_capacity(offset.vertex_count(), offset.is_closed());
+
+        for (index, vertex) in offset.iter_vertexes().enumerate() {
+            let is_last = index + 1 == offset.vertex_count();
+            if vertex.bulge_is_zero() || (is_last && !offset.is_closed()) {
+                joined.add_vertex(vertex);
+                continue;
+            }
+
+            let next = offset.at(offset.next_wrapping_index(index));
+            let (radius, center) = seg_arc_radius_and_center(vertex, next);
+            let is_join = (radius - distance).abs() <= 1e-6
+                && corners
+                    .iter()
+                    .any(|corner| corner.fuzzy_eq_eps(center, 1e-6));
+            if !is_join {
+                joined.add_vertex(vertex);
+                continue;
+            }
+
+            joined.add(vertex.x, vertex.y, 0.0);
+            // The miter lies on the bisector of the arc, `1 / cos(sweep / 2)` times the
+            // distance away from the corner.
+            let half_sweep = 2.0 * vertex.bulge.abs().atan();
+            if let JoinStyle::Miter { limit } = self.join_style
+                && half_sweep.cos() * limit >= 1.0
+            {
+                let bisector = ((vertex.pos() - center) + (next.pos() - center)).normalize();
+                let miter = center + bisector.scale(distance / half_sweep.cos());
+                joined.add(miter.x, miter.y, 0.0);
+            }
+        }
+
+        // The ends of the offset segments are left in line with a miter.
+        joined.remove_redundant(1e-9).unwrap_or(joined)
+    }
+}
+
+impl Offset for CavalierContours {
+    /// Offsets every subpath of the path and collects all resulting contours.
+    ///
+    /// Returns [`PathError::EmptyResult`] if no contour is left, as when every subpath is
+    /// shrunk by more than its inradius.
+    fn offset_path(&self, path: &Path) -> Result<Path> {
+        let result: Path = path
+            .iter()
+            .flat_map(|subpath| {
+                let source = Polyline::from(&subpath);
+                if let (Some(cap_style), false) = (self.cap_style, source.is_closed()) {
+                    return self.stroke(&source, cap_style);
+                }
+
+                // `cavalier_contours` offsets to the left of the direction of travel, which
+                // shrinks counter-clockwise polylines.
+                let offsets = source.parallel_offset(-self.offset_distance);
+                match self.join_style {
+                    JoinStyle::Round => offsets,
+                    _ => {
+                        let corners: Vec<Vector2<f64>> =
+                            source.iter_vertexes().map(|vertex| vertex.pos()).collect();
+                        offsets
+                            .iter()
+                            .map(|offset| self.apply_join_style(offset, &corners))
+                            .collect()
+                    }
+                }
+            })
+            .filter(|polyline| polyline.vertex_count() > 1)
+            .map(|polyline| Path::from_polyline(&polyline, self.arc_segments_per_quadrant))
+            .collect();
+
+        if result.subpath_count() == 0 && path.subpath_count() > 0 {
+            return Err(PathError::EmptyResult);
+        }
+        Ok(result.with_scalars_from(path))
+    }
+}
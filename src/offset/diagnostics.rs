@@ -0,0 +1,118 @@
+//! Reports how an offset treated the corners of its source path.
+//!
+//! When a path is offset, the offset segments on either side of a source vertex either
+//! separate, leaving a gap that has to be bridged by a join, or overlap, in which case
+//! the overlap is trimmed away. [`OffsetDiagnostics`] lists which source vertices fell
+//! into each group, which is useful for verifying machining toolpaths.
+
+use crate::path::{Path, event::PathEvent, point::Point};
+
+/// Classification of the source vertices of an offset.
+///
+/// Vertices are numbered in path order across all subpaths: the start point of each
+/// subpath followed by the end point of each of its segments. Vertices where the path
+/// continues straight on, and the endpoints of open subpaths, appear in neither list.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OffsetDiagnostics {
+    /// Vertices where the offset segments separate and a round join bridges the gap.
+    /// These are the convex corners relative to the offset direction.
+    pub round_joins: Vec<usize>,
+    /// Vertices where the offset segments overlap and are trimmed back to their
+    /// intersection. These are the concave corners relative to the offset direction.
+    pub trimmed: Vec<usize>,
+}
+
+/// Classifies the corners of `path` for an offset by `distance`.
+///
+/// A positive distance offsets to the right of the direction of travel, which grows
+/// subpaths with a positive signed area. A corner that turns away from the offset side
+/// opens a gap that needs a join; a corner that turns towards it is trimmed.
+pub(crate) fn classify_corners(path: &Path, distance: f64) -> OffsetDiagnostics {
+    let mut diagnostics = OffsetDiagnostics::default();
+    let mut next_vertex = 0;
+
+    for subpath in path.iter() {
+        let start_vertex = next_vertex;
+        // Each segment as (index of its end vertex, start tangent, end tangent).
+        let mut segments: Vec<(usize, Point, Point)> = Vec::new();
+        let mut closed = false;
+
+        for event in subpath.events() {
+            match event {
+                PathEvent::Begin { .. } => next_vertex += 1,
+                PathEvent::End { last, first, close } => {
+                    closed = close;
+                    // The implicit closing segment ends back at the start vertex.
+                    if let (true, Some(tangent)) = (close, direction(last, first)) {
+                        segments.push((start_vertex, tangent, tangent));
+                    }
+                }
+                segment => {
+                    if let Some((start, end)) = segment_tangents(&segment) {
+                        segments.push((next_vertex, start, end));
+                    }
+                    next_vertex += 1;
+                }
+            }
+        }
+
+        // A corner sits at the end vertex of its incoming segment.
+        let mut corners: Vec<(usize, Point, Point)> = segments
+            .windows(2)
+            .map(|pair| (pair[0].0, pair[0].2, pair[1].1))
+            .collect();
+        if let (true, [first, .., last]) = (closed, segments.as_slice()) {
+            corners.push((last.0, last.2, first.1));
+        }
+
+        for (vertex, incoming, outgoing) in corners {
+            let turn = (incoming.0 * outgoing.1 - incoming.1 * outgoing.0) * distance;
+            if turn > 1e-9 {
+                diagnostics.round_joins.push(vertex);
+            } else if turn < -1e-9 {
+                diagnostics.trimmed.push(vertex);
+            }
+        }
+    }
+
+    diagnostics.round_joins.sort_unstable();
+    diagnostics.trimmed.sort_unstable();
+    diagnostics
+}
+
+/// Returns the unit tangents at the start and end of a segment event.
+fn segment_tangents(event: &PathEvent) -> Option<(Point, Point)> {
+    match *event {
+        PathEvent::Line { from, to } => {
+            let tangent = direction(from, to)?;
+            Some((tangent, tangent))
+        }
+        PathEvent::Quadratic { from, ctrl, to } => Some((
+            first_direction(from, &[ctrl, to])?,
+            -first_direction(to, &[ctrl, from])?,
+        )),
+        PathEvent::Cubic {
+            from,
+            ctrl1,
+            ctrl2,
+            to,
+        } => Some((
+            first_direction(from, &[ctrl1, ctrl2, to])?,
+            -first_direction(to, &[ctrl2, ctrl1, from])?,
+        )),
+        PathEvent::Begin { .. } | PathEvent::End { .. } => None,
+    }
+}
+
+/// Returns the unit direction from `from` towards the first of `candidates` that differs from it.
+fn first_direction(from: Point, candidates: &[Point]) -> Option<Point> {
+    candidates
+        .iter()
+        .find_map(|candidate| direction(from, *candidate))
+}
+
+/// Returns the unit direction from `from` to `to`, or `None` if the points coincide.
+fn direction(from: Point, to: Point) -> Option<Point> {
+    let length = from.distance_to(to);
+    (length > 1e-9).then(|| (to - from) * (1.0 / length))
+}
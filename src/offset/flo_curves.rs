@@ -4,7 +4,7 @@
 //! library to perform path offsetting.
 
 use flo_curves::{
-    BezierCurve, Coord2,
+    BezierCurve, BezierCurveFactory, Coord2, Coordinate, Coordinate2D,
     bezier::{
         Curve, curve_is_tiny, fit_curve, offset,
         path::{BezierPath, BezierPathFactory, SimpleBezierPath, path_remove_interior_points},
@@ -14,8 +14,15 @@ use flo_curves::{
 
 use crate::{
     error::{PathError, Result},
-    offset::Offset,
-    path::Path,
+    offset::{
+        CapStyle, JoinStyle, Offset,
+        cavalier_contours::CavalierContours,
+        diagnostics::{OffsetDiagnostics, classify_corners},
+    },
+    path::{
+        Path,
+        conversions::cavalier_contours::{DEFAULT_ARC_SEGMENTS_PER_QUADRANT, arc_curves},
+    },
 };
 
 /// A path offsetter that uses the `flo_curves` library.
@@ -23,87 +30,1023 @@ use crate::{
 /// This struct encapsulates the logic for offsetting a path using the algorithms
 /// provided by the `flo_curves` library.
 pub struct FloCurvesOffset {
-    curves: Vec<Curve<Coord2>>,
+    offset_distance: f64,
+    sort_by_area: bool,
+    join_style: Option<JoinStyle>,
+    miter_limit: f64,
+    clean_tolerance: f64,
+    sample_distance: f64,
+    sample_max_error: f64,
+    fit_tolerance: f64,
+    winding_check: Option<WindingCheck>,
+    cap_style: Option<CapStyle>,
+    arc_segments_per_quadrant: usize,
+}
+
+/// What [`FloCurvesOffset::with_winding_check`] does with input of mixed winding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindingCheck {
+    /// Fails with [`PathError::Unsupported`].
+    Reject,
+    /// Reverses the subpaths that disagree with their role, with [`Path::normalize_winding`].
+    Normalize,
 }
 
+/// The default miter limit of [`FloCurvesOffset::with_miter_limit`]. This matches the
+/// default `stroke-miterlimit` of SVG.
+const DEFAULT_MITER_LIMIT: f64 = 4.0;
+
 impl FloCurvesOffset {
     /// Creates a new `FloCurvesOffset` instance.
     ///
     /// # Arguments
     ///
-    /// * `path` - A reference to the `Path` to be offset.
-    /// * `offset_distance` - The distance by which to offset the path.
-    pub fn new(path: &Path, offset_distance: f64) -> Self {
+    /// * `offset_distance` - The distance by which to offset paths.
+    pub fn new(offset_distance: f64) -> Self {
         FloCurvesOffset {
-            curves: SimpleBezierPath::from(path)
-                .to_curves()
-                .into_iter()
-                .flat_map(|curve| offset(&curve, -offset_distance, -offset_distance))
-                .filter(|curve| !curve_is_tiny(curve))
-                .collect::<Vec<_>>(),
+            offset_distance,
+            sort_by_area: false,
+            join_style: None,
+            miter_limit: DEFAULT_MITER_LIMIT,
+            clean_tolerance: 0.01,
+            sample_distance: 0.1,
+            sample_max_error: 0.01,
+            fit_tolerance: 1.0,
+            winding_check: None,
+            cap_style: None,
+            arc_segments_per_quadrant: DEFAULT_ARC_SEGMENTS_PER_QUADRANT,
+        }
+    }
+
+    /// Makes `offset_path` check the winding of its input before offsetting.
+    ///
+    /// Offsetting moves every subpath to the same side of its direction of travel, so a path
+    /// whose shells or holes wind inconsistently would be grown in some places and shrunk in
+    /// others. With this check enabled, such input is detected with
+    /// [`Path::has_mixed_winding`] before offsetting.
+    ///
+    /// # Arguments
+    ///
+    /// * `winding_check` - Whether mixed winding is rejected or fixed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::error::PathError;
+    /// use path_offset::offset::{
+    ///     Offset,
+    ///     flo_curves::{FloCurvesOffset, WindingCheck},
+    /// };
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// // Two separate shells, the smaller second one wound the other way around.
+    /// let path = Path::from_str("M0,0 L12,0 L12,12 L0,12 Z M20,0 L20,10 L30,10 L30,0 Z").unwrap();
+    ///
+    /// let result = FloCurvesOffset::new(1.0).with_winding_check(WindingCheck::Reject).offset_path(&path);
+    /// assert!(matches!(result, Err(PathError::Unsupported(_))));
+    ///
+    /// let offset_path = FloCurvesOffset::new(1.0)
+    ///     .with_winding_check(WindingCheck::Normalize)
+    ///     .offset_path(&path)
+    ///     .unwrap();
+    /// // The smaller shell is turned to match the larger one, so both are grown.
+    /// assert_eq!(offset_path.subpath_count(), 2);
+    /// for shell in offset_path.iter() {
+    ///     assert!(shell.signed_area(0.01).abs() > 100.0);
+    /// }
+    /// ```
+    pub fn with_winding_check(mut self, winding_check: WindingCheck) -> Self {
+        self.winding_check = Some(winding_check);
+        self
+    }
+
+    /// Sets the accuracy used when removing interior points from the offset result.
+    ///
+    /// `flo_curves` treats intersections closer together than this tolerance as one. The
+    /// default of `0.01` also holds up for coordinates in the tens of thousands and beyond,
+    /// where the `f32` rounding of the path already exceeds it: a larger value does not
+    /// remove more interior points there, and it merges nearby intersections that should
+    /// stay apart, leaving extra loops. Raise it only for geometry whose intersections are
+    /// genuinely too close together to be told apart at the default accuracy.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `clean_tolerance` is not a positive, finite number.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::offset::{Offset, flo_curves::FloCurvesOffset};
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// // A self-intersecting pentagram with coordinates in the tens of thousands.
+    /// let star = Path::from_str(
+    ///     "M0,10000 L-5877.853,-8090.17 L9510.565,3090.17 L-9510.565,3090.17 L5877.853,-8090.17 Z",
+    /// )
+    /// .unwrap();
+    ///
+    /// // The default cleans the offset into a single outline.
+    /// let offset_path = FloCurvesOffset::new(100.0).offset_path(&star).unwrap();
+    /// assert_eq!(offset_path.subpath_count(), 1);
+    /// assert!(offset_path.self_intersections(1.0).is_empty());
+    ///
+    /// // A coarser tolerance leaves loops behind on the same input.
+    /// let offset_path = FloCurvesOffset::new(100.0)
+    ///     .with_clean_tolerance(1.0)
+    ///     .offset_path(&star)
+    ///     .unwrap();
+    /// assert!(offset_path.subpath_count() > 1);
+    /// ```
+    pub fn with_clean_tolerance(mut self, clean_tolerance: f64) -> Self {
+        assert!(
+            clean_tolerance.is_finite() && clean_tolerance > 0.0,
+            "the clean tolerance must be positive and finite"
+        );
+        self.clean_tolerance = clean_tolerance;
+        self
+    }
+
+    /// Sets the distance between the points sampled along the offset curves.
+    ///
+    /// `flo_curves` offsets each curve only approximately, so the offset curves are
+    /// sampled every `sample_distance` and a new curve is fitted through the samples (see
+    /// [`FloCurvesOffset::with_fit_tolerance`]). The defaults, a distance of `0.1` with a
+    /// maximum error of `0.01` and a fit tolerance of `1.0`, suit paths whose coordinates
+    /// are in the tens or hundreds. Tightening all three follows the ideal offset more
+    /// closely, as sub-millimeter work needs, at the cost of more samples and more fitted
+    /// curves. For very large paths, loosening them makes offsetting much faster.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sample_distance` is not a positive, finite number.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::offset::{Offset, flo_curves::FloCurvesOffset};
+    /// use path_offset::path::{Path, point::Point};
+    /// use std::str::FromStr;
+    ///
+    /// let circle = Path::from_str("M10,0 A10,10 0 1 1 -10,0 A10,10 0 1 1 10,0 Z").unwrap();
+    /// // The largest distance between the offset and the ideal circle of radius 11.
+    /// let error = |offsetter: FloCurvesOffset| {
+    ///     let offset_path = offsetter.offset_path(&circle).unwrap();
+    ///     offset_path
+    ///         .flatten(0.001)
+    ///         .events()
+    ///         .filter_map(|event| match event {
+    ///             path_offset::path::event::PathEvent::Line { to, .. } => Some(to),
+    ///             _ => None,
+    ///         })
+    ///         .map(|point| (point.distance_to(Point(0.0, 0.0)) - 11.0).abs())
+    ///         .fold(0.0, f64::max)
+    /// };
+    ///
+    /// let coarse = error(FloCurvesOffset::new(1.0));
+    /// let fine = error(
+    ///     FloCurvesOffset::new(1.0)
+    ///         .with_sample_distance(0.05)
+    ///         .with_sample_max_error(0.001)
+    ///         .with_fit_tolerance(0.01),
+    /// );
+    /// assert!(fine < coarse);
+    /// assert!(fine < 0.02);
+    /// ```
+    pub fn with_sample_distance(mut self, sample_distance: f64) -> Self {
+        assert!(
+            sample_distance.is_finite() && sample_distance > 0.0,
+            "the sample distance must be positive and finite"
+        );
+        self.sample_distance = sample_distance;
+        self
+    }
+
+    /// Sets the maximum error allowed when walking the offset curves to sample them.
+    ///
+    /// Samples are placed within this distance of the positions a perfectly even walk would
+    /// give. The default is `0.01`; see [`FloCurvesOffset::with_sample_distance`] for the
+    /// tradeoff between accuracy and speed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sample_max_error` is not a positive, finite number.
+    pub fn with_sample_max_error(mut self, sample_max_error: f64) -> Self {
+        assert!(
+            sample_max_error.is_finite() && sample_max_error > 0.0,
+            "the sample maximum error must be positive and finite"
+        );
+        self.sample_max_error = sample_max_error;
+        self
+    }
+
+    /// Sets the maximum distance between the samples of the offset curves and the curves
+    /// fitted through them.
+    ///
+    /// The default is `1.0`; see [`FloCurvesOffset::with_sample_distance`] for the tradeoff
+    /// between accuracy and speed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fit_tolerance` is not a positive, finite number.
+    ///
+    /// # Example
+    ///
+    /// ```should_panic
+    /// use path_offset::offset::flo_curves::FloCurvesOffset;
+    ///
+    /// // A tolerance of zero could never be met.
+    /// FloCurvesOffset::new(1.0).with_fit_tolerance(0.0);
+    /// ```
+    pub fn with_fit_tolerance(mut self, fit_tolerance: f64) -> Self {
+        assert!(
+            fit_tolerance.is_finite() && fit_tolerance > 0.0,
+            "the fit tolerance must be positive and finite"
+        );
+        self.fit_tolerance = fit_tolerance;
+        self
+    }
+
+    /// Sets whether `offset_path` returns the resulting loops sorted by descending area.
+    ///
+    /// Offsetting self-intersecting input can produce several loops once the interior
+    /// points are removed, and their order is otherwise arbitrary. With sorting enabled,
+    /// the dominant contour comes first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::offset::{Offset, flo_curves::FloCurvesOffset};
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// // A dumbbell whose inward offset breaks up into several loops.
+    /// let path = Path::from_str(
+    ///     "M0,0 L10,0 L10,4 L20,4 L20,0 L30,0 L30,10 L20,10 L20,6 L10,6 L10,10 L0,10 Z",
+    /// )
+    /// .unwrap();
+    /// let offset_path = FloCurvesOffset::new(-1.0)
+    ///     .sort_by_area(true)
+    ///     .offset_path(&path)
+    ///     .unwrap();
+    ///
+    /// // The lobes come first and the small debris loops last.
+    /// let loops: Vec<Path> = offset_path.iter().collect();
+    /// let is_lobe = |path: &Path| path.remove_small_subpaths(30.0, 0.01).subpath_count() == 1;
+    /// assert!(loops.len() > 2);
+    /// assert!(is_lobe(&loops[0]) && is_lobe(&loops[1]));
+    /// assert!(loops[2..].iter().all(|path| !is_lobe(path)));
+    /// ```
+    pub fn sort_by_area(mut self, sort_by_area: bool) -> Self {
+        self.sort_by_area = sort_by_area;
+        self
+    }
+
+    /// Sets whether `offset_path` keeps the sharp corners of the source as exact vertices.
+    ///
+    /// By default every corner is rounded, because the offset curves are sampled and refitted
+    /// as one smooth curve. With this mode enabled, the offset vertex of each sharp source
+    /// corner is computed analytically as the intersection of the offset lines on either
+    /// side of it, and the refit is split at those vertices so the result passes through
+    /// them exactly. This suits shapes such as rectangular pockets.
+    ///
+    /// Convex corners follow the semantics of SVG's `stroke-linejoin="miter"`: a corner
+    /// whose offset vertex would lie too far from the source corner is bevelled instead (see
+    /// [`FloCurvesOffset::with_miter_limit`]). Concave corners beyond the limit are rounded.
+    /// This is a shorthand for [`FloCurvesOffset::with_join_style`] with
+    /// [`JoinStyle::Miter`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::offset::{Offset, flo_curves::FloCurvesOffset};
+    /// use path_offset::path::{Path, event::PathEvent, point::Point};
+    /// use std::str::FromStr;
+    ///
+    /// let path = Path::from_str("M0,0 L10,0 L10,10 L0,10 Z").unwrap();
+    /// let offset_path = FloCurvesOffset::new(1.0)
+    ///     .preserve_corners(true)
+    ///     .offset_path(&path)
+    ///     .unwrap();
+    ///
+    /// let vertices: Vec<Point> = offset_path
+    ///     .events()
+    ///     .filter_map(|event| match event {
+    ///         PathEvent::Begin { at } => Some(at),
+    ///         PathEvent::Line { to, .. } | PathEvent::Cubic { to, .. } => Some(to),
+    ///         _ => None,
+    ///     })
+    ///     .collect();
+    /// for corner in [Point(-1.0, -1.0), Point(11.0, -1.0), Point(11.0, 11.0), Point(-1.0, 11.0)] {
+    ///     assert!(vertices.contains(&corner));
+    /// }
+    ///
+    /// // Paths without any segments have no offset.
+    /// for empty in ["", "M0,0", "M0,0 L0,0"] {
+    ///     let offset_path = FloCurvesOffset::new(1.0)
+    ///         .preserve_corners(true)
+    ///         .offset_path(&Path::from_str(empty).unwrap())
+    ///         .unwrap();
+    ///     assert_eq!(offset_path.subpath_count(), 0);
+    /// }
+    /// ```
+    pub fn preserve_corners(mut self, preserve_corners: bool) -> Self {
+        self.join_style = preserve_corners.then_some(JoinStyle::Miter {
+            limit: self.miter_limit,
+        });
+        self
+    }
+
+    /// Sets how the offset curves on either side of each sharp corner are joined.
+    ///
+    /// Like [`FloCurvesOffset::preserve_corners`], this computes the offset of each sharp
+    /// source corner analytically and splits the refit at it. Convex corners are then joined
+    /// according to `join_style`, while concave corners are trimmed back to the intersection
+    /// of their offset curves. Concave corners so sharp that this intersection lies further
+    /// than the miter limit from the source corner are rounded; the limit is that of
+    /// [`JoinStyle::Miter`], or [`FloCurvesOffset::with_miter_limit`] for the other styles.
+    ///
+    /// Open subpaths need a cap style (see [`FloCurvesOffset::with_cap_style`]) to be
+    /// outlined; without one they are rejected with [`PathError::Unsupported`] rather than
+    /// joined back to their start.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::error::PathError;
+    /// use path_offset::offset::{CapStyle, JoinStyle, Offset, flo_curves::FloCurvesOffset};
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// let offsetter = FloCurvesOffset::new(1.0).with_join_style(JoinStyle::Round);
+    /// let point = Path::from_str("M0,0 L0,0").unwrap();
+    /// assert_eq!(offsetter.offset_path(&point).unwrap().subpath_count(), 0);
+    ///
+    /// let line = Path::from_str("M0,0 L10,0 L10,10").unwrap();
+    /// assert!(matches!(offsetter.offset_path(&line), Err(PathError::Unsupported(_))));
+    /// let outline = offsetter.with_cap_style(CapStyle::Butt).offset_path(&line).unwrap();
+    /// assert!(outline.is_closed());
+    /// ```
+    pub fn with_join_style(mut self, join_style: JoinStyle) -> Self {
+        self.join_style = Some(join_style);
+        self
+    }
+
+    /// Sets how open subpaths are outlined.
+    ///
+    /// By default every subpath is treated as closed. With a cap style, open subpaths are
+    /// instead outlined on both sides at the offset distance and capped around their ends,
+    /// producing closed outlines. `flo_curves` has no notion of caps, so these outlines are
+    /// computed exactly from lines and arcs by
+    /// [`CavalierContours::with_cap_style`], using this offsetter's join style at corners.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::offset::{CapStyle, Offset, flo_curves::FloCurvesOffset};
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// let line = Path::from_str("M0,0 L10,0").unwrap();
+    /// let outline = FloCurvesOffset::new(2.0)
+    ///     .with_cap_style(CapStyle::Round)
+    ///     .offset_path(&line)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(outline.subpath_count(), 1);
+    /// assert!(outline.is_closed());
+    /// let (min, max) = outline.report(0.01).bounds.unwrap();
+    /// assert_eq!((min.0, min.1, max.0, max.1), (-2.0, -2.0, 12.0, 2.0));
+    ///
+    /// // The outlines keep their place among the offsets of the closed subpaths, and
+    /// // outlive a closed subpath that the offset collapses.
+    /// let path = Path::from_str("M100,0 L110,0 M0,0 L10,0 L10,10 L0,10 Z").unwrap();
+    /// let offset_path = FloCurvesOffset::new(2.0)
+    ///     .with_cap_style(CapStyle::Butt)
+    ///     .offset_path(&path)
+    ///     .unwrap();
+    /// assert_eq!(offset_path.subpath_count(), 2);
+    /// assert_eq!(offset_path.subpath(0).unwrap().bounding_box().unwrap().0.0, 100.0);
+    ///
+    /// let offsetter = FloCurvesOffset::new(-2.0).with_cap_style(CapStyle::Butt);
+    /// let path = Path::from_str("M100,0 L110,0 M0,0 L2,0 L2,2 L0,2 Z").unwrap();
+    /// assert_eq!(offsetter.offset_path(&path).unwrap().subpath_count(), 1);
+    /// ```
+    pub fn with_cap_style(mut self, cap_style: CapStyle) -> Self {
+        self.cap_style = Some(cap_style);
+        self
+    }
+
+    /// Sets how many cubic curves approximate each quarter turn of the round joins.
+    ///
+    /// With [`JoinStyle::Round`], each convex corner is bridged by a circular arc, which the
+    /// offset path approximates with cubic curves, as closely as
+    /// [`Path::from_polyline`](crate::path::Path::from_polyline) describes. The default is
+    /// `1`. The setting is also used for the outlines of open subpaths (see
+    /// [`CavalierContours::with_arc_segments_per_quadrant`]). A value of `0` is treated as
+    /// `1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::offset::{JoinStyle, Offset, flo_curves::FloCurvesOffset};
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// // Growing a square by 10 rounds each corner with a quarter circle of radius 10.
+    /// let square = Path::from_str("M0,0 L10,0 L10,10 L0,10 Z").unwrap();
+    /// let grown = |arc_segments_per_quadrant: usize| {
+    ///     FloCurvesOffset::new(10.0)
+    ///         .with_join_style(JoinStyle::Round)
+    ///         .with_arc_segments_per_quadrant(arc_segments_per_quadrant)
+    ///         .offset_path(&square)
+    ///         .unwrap()
+    /// };
+    /// // The largest distance of the corners from their circles.
+    /// let deviation = |path: &Path| {
+    ///     path.to_polygon_rings(0.00001)
+    ///         .iter()
+    ///         .flatten()
+    ///         .map(|&[x, y]| {
+    ///             let corner = (x.clamp(0.0, 10.0), y.clamp(0.0, 10.0));
+    ///             ((x - corner.0).hypot(y - corner.1) - 10.0).abs()
+    ///         })
+    ///         .fold(0.0, f64::max)
+    /// };
+    ///
+    /// let (coarse, fine) = (grown(1), grown(4));
+    /// assert_eq!(fine.segment_count(), coarse.segment_count() + 4 * 3);
+    /// assert!(deviation(&coarse) > 0.002);
+    /// assert!(deviation(&fine) < 0.0001);
+    /// ```
+    pub fn with_arc_segments_per_quadrant(mut self, arc_segments_per_quadrant: usize) -> Self {
+        self.arc_segments_per_quadrant = arc_segments_per_quadrant;
+        self
+    }
+
+    /// Sets the miter limit used by [`FloCurvesOffset::preserve_corners`].
+    ///
+    /// A convex corner is joined with a miter as long as its offset vertex lies within
+    /// `miter_limit × distance` of the source corner, and with a bevel otherwise. This is the
+    /// same rule as SVG's `stroke-miterlimit`, whose default of `4.0` is also used here.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::offset::{Offset, flo_curves::FloCurvesOffset};
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// // The tip at (20,2) is so sharp that its miter reaches about 10.05 past it.
+    /// let path = Path::from_str("M0,0 L20,2 L0,4 Z").unwrap();
+    /// let max_x = |miter_limit: f64| {
+    ///     let offset_path = FloCurvesOffset::new(1.0)
+    ///         .preserve_corners(true)
+    ///         .with_miter_limit(miter_limit)
+    ///         .offset_path(&path)
+    ///         .unwrap();
+    ///     offset_path.report(0.01).bounds.unwrap().1.0
+    /// };
+    ///
+    /// // A low limit bevels the tip just past the source corner...
+    /// assert!((max_x(2.0) - 20.1).abs() < 0.01);
+    /// // ...while a high limit keeps the miter.
+    /// assert!((max_x(20.0) - 30.05).abs() < 0.01);
+    /// ```
+    pub fn with_miter_limit(mut self, miter_limit: f64) -> Self {
+        self.miter_limit = miter_limit;
+        if let Some(JoinStyle::Miter { limit }) = &mut self.join_style {
+            *limit = miter_limit;
         }
+        self
+    }
+
+    /// Offsets the path and reports how each source corner was treated.
+    ///
+    /// The `flo_curves` pipeline has no explicit join step: at convex corners the gap between
+    /// neighbouring offset curves is bridged by the refitted curve, which rounds the corner,
+    /// and at concave corners the overlapping curves are trimmed by the interior-point
+    /// cleanup. The returned [`OffsetDiagnostics`] lists the source vertices in each group.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::offset::flo_curves::FloCurvesOffset;
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// // An L-shape whose only concave corner is vertex 3 at (10,10).
+    /// let path = Path::from_str("M0,0 L20,0 L20,10 L10,10 L10,20 L0,20 Z").unwrap();
+    /// let (_, diagnostics) = FloCurvesOffset::new(1.0)
+    ///     .offset_path_with_diagnostics(&path)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(diagnostics.round_joins, [0, 1, 2, 4, 5]);
+    /// assert_eq!(diagnostics.trimmed, [3]);
+    /// ```
+    pub fn offset_path_with_diagnostics(&self, path: &Path) -> Result<(Path, OffsetDiagnostics)> {
+        Ok((
+            self.offset_path(path)?,
+            classify_corners(path, self.offset_distance),
+        ))
     }
 
-    /// Returns a reference to the underlying `flo_curves` curves.
-    pub fn curves(&self) -> &Vec<Curve<Coord2>> {
-        &self.curves
+    /// Returns the raw `flo_curves` offset curves of `path`, before they are refitted and
+    /// cleaned into the result of `offset_path`.
+    pub fn curves(&self, path: &Path) -> Vec<Curve<Coord2>> {
+        path.iter()
+            .flat_map(|subpath| offset_curves(&source_curves(&subpath), self.offset_distance))
+            .collect()
+    }
+
+    /// Offsets the path by a distance that varies along it, ignoring the offset distance
+    /// this offsetter was created with.
+    ///
+    /// `width_fn` maps a position along a subpath to the offset distance there, with the
+    /// same sign convention as the constant distance. The position `t` is the length
+    /// travelled from the start of the subpath divided by its total length, so it runs from
+    /// `0` to `1` over the whole subpath rather than over each segment: every segment covers
+    /// a share of that range proportional to its length, and a closed subpath includes its
+    /// closing segment, ending at `t = 1` back at its start. Every subpath gets its own range
+    /// from `0` to `1`. For a closed subpath, `width_fn(0.0)` should equal `width_fn(1.0)` to
+    /// avoid a step at the start point.
+    ///
+    /// The source curves are walked every `sample_distance` (see
+    /// [`FloCurvesOffset::with_sample_distance`]), each sample is moved along the normal by
+    /// the distance at its position, and curves are fitted through the moved samples and
+    /// cleaned as in `offset_path`. Corners are rounded, whatever the join style.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::offset::flo_curves::FloCurvesOffset;
+    /// use path_offset::path::Path;
+    /// use std::f64::consts::TAU;
+    /// use std::str::FromStr;
+    ///
+    /// // A circle of radius 10, starting on the right and running counter-clockwise.
+    /// let circle = Path::from_str("M10,0 A10,10 0 1 1 -10,0 A10,10 0 1 1 10,0 Z").unwrap();
+    ///
+    /// // 2 units wide at the start, tapering to nothing halfway around, and back.
+    /// let offset_path = FloCurvesOffset::new(0.0)
+    ///     .with_fit_tolerance(0.001)
+    ///     .offset_variable(&circle, |t| 1.0 + (t * TAU).cos())
+    ///     .unwrap();
+    ///
+    /// let (min, max) = offset_path.bounding_box().unwrap();
+    /// assert!((max.0 - 12.0).abs() < 0.1);
+    /// assert!((min.0 + 10.0).abs() < 0.1);
+    /// ```
+    pub fn offset_variable(&self, path: &Path, width_fn: impl Fn(f64) -> f64) -> Result<Path> {
+        let loops = path
+            .iter()
+            .map(|subpath| {
+                // The sections of the source curves, with their lengths.
+                let sections: Vec<(Coord2, Coord2, f64)> = source_curves(&subpath)
+                    .iter()
+                    .flat_map(|curve| {
+                        walk_curve_evenly(curve, self.sample_distance, self.sample_max_error)
+                            .map(|section| {
+                                let (start, end) = (section.start_point(), section.end_point());
+                                (
+                                    section.point_at_pos(0.5),
+                                    end - start,
+                                    start.distance_to(&end),
+                                )
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                    .collect();
+                let total: f64 = sections.iter().map(|(_, _, length)| length).sum();
+
+                let mut travelled = 0.0;
+                let offset_points: Vec<Coord2> = sections
+                    .iter()
+                    .filter(|(_, _, length)| *length > 0.0)
+                    .map(|&(point, chord, length)| {
+                        let width = width_fn((travelled + length / 2.0) / total);
+                        travelled += length;
+                        // A positive distance moves to the right of the direction of travel.
+                        let normal = Coord2(chord.y(), -chord.x()) * (1.0 / length);
+                        point + normal * width
+                    })
+                    .collect();
+
+                let fitted_curve = fit_curve::<Curve<Coord2>>(&offset_points, self.fit_tolerance)
+                    .ok_or(PathError::FitCurve)?;
+                self.clean(fitted_curve)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let offset_path = self.collect_loops(loops.iter().flatten().map(Path::from).collect());
+        Ok(offset_path.with_scalars_from(path))
     }
 }
 
 impl Offset for FloCurvesOffset {
     /// Offsets the path using the `flo_curves` library.
     ///
-    /// This method takes the curves generated during the creation of the `FloCurvesOffset` instance,
-    /// samples them, fits a new curve to the sampled points, and then cleans the resulting path
-    /// to produce the final offset path.
+    /// This method offsets each curve of the path, samples the offset curves, fits a new
+    /// curve to the sampled points, and then cleans the resulting path to produce the final
+    /// offset path. Every subpath is offset on its own, so shells and holes are all kept.
+    ///
+    /// Cleaning can split the offset into several loops, for example when an inward offset
+    /// pinches a shape into separate regions. Every loop is kept as a subpath of the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::offset::{Offset, flo_curves::FloCurvesOffset};
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// // An hourglass whose 2-unit-wide waist disappears when offset inward by 1.5.
+    /// let path = Path::from_str("M20,0 L11,10 L20,20 L0,20 L9,10 L0,0 Z").unwrap();
+    /// let offset_path = FloCurvesOffset::new(-1.5).offset_path(&path).unwrap();
+    ///
+    /// // Both halves survive, ignoring the smaller debris loops left behind by cleaning.
+    /// let halves = offset_path.remove_small_subpaths(30.0, 0.01);
+    /// assert_eq!(halves.subpath_count(), 2);
+    /// ```
+    ///
+    /// A dumbbell splits into its two lobes in the same way:
+    ///
+    /// ```
+    /// use path_offset::offset::{Offset, flo_curves::FloCurvesOffset};
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// // Two 10x10 lobes joined by a 2-unit-wide neck.
+    /// let path = Path::from_str(
+    ///     "M0,0 L10,0 L10,4 L20,4 L20,0 L30,0 L30,10 L20,10 L20,6 L10,6 L10,10 L0,10 Z",
+    /// )
+    /// .unwrap();
+    /// let offset_path = FloCurvesOffset::new(-1.5).offset_path(&path).unwrap();
+    ///
+    /// let lobes = offset_path.remove_small_subpaths(30.0, 0.01);
+    /// assert_eq!(lobes.subpath_count(), 2);
+    /// // Each lobe shrinks to about a 7x7 square, one on either side of the neck.
+    /// let (left, right) = (lobes.subpath(0).unwrap(), lobes.subpath(1).unwrap());
+    /// assert!((left.signed_area(0.01).abs() - 49.0).abs() < 1.0);
+    /// assert!((right.signed_area(0.01).abs() - 49.0).abs() < 1.0);
+    /// assert!(left.bounding_box().unwrap().1.0 < 10.0);
+    /// assert!(right.bounding_box().unwrap().0.0 > 20.0 - 1.5);
+    /// ```
     ///
     /// # Returns
     ///
     /// A `Result` containing the offset `Path` or an error if the offsetting process fails.
-    fn offset_path(&self) -> Result<Path> {
-        let offset_points = self
-            .curves
-            .iter()
-            .flat_map(|curve| sample_curve(curve))
-            .collect::<Vec<_>>();
+    ///
+    /// `flo_curves` does not notice when a shape collapses: shrinking it past its inradius
+    /// leaves debris loops rather than nothing. A subpath shrunk by at least half its smaller
+    /// bounding box dimension is therefore dropped up front, and [`PathError::EmptyResult`]
+    /// is returned if every subpath is dropped. Smaller collapses, such as a thin diagonal
+    /// sliver, may still leave debris behind.
+    fn offset_path(&self, path: &Path) -> Result<Path> {
+        // Joins are placed at the corners of the closed loop, which an open subpath would only
+        // get by looping back on itself, unless a cap style outlines it instead.
+        if self.join_style.is_some() && self.cap_style.is_none() {
+            path.validate_for_offset()?;
+        }
+
+        // Open subpaths outlined with a cap style take no part in the winding of the rest.
+        let outlined = |subpath: &Path| self.cap_style.is_some() && !subpath.is_closed();
+        let closed: Path = path.iter().filter(|subpath| !outlined(subpath)).collect();
+        let closed = match self.winding_check {
+            Some(WindingCheck::Normalize) => closed.normalize_winding(),
+            Some(WindingCheck::Reject) => {
+                closed.validate_winding()?;
+                closed
+            }
+            None => closed,
+        };
+        let mut closed = closed.iter();
+
+        // Each subpath is offset on its own, as `flo_curves` offsets a single contour.
+        let mut loops = Vec::new();
+        let mut collapsed = false;
+        for subpath in path.iter() {
+            let subpath = match self.cap_style {
+                Some(cap_style) if outlined(&subpath) => {
+                    let outline = CavalierContours::new(self.offset_distance)
+                        .with_join_style(self.join_style.unwrap_or(JoinStyle::Round))
+                        .with_cap_style(cap_style)
+                        .with_arc_segments_per_quadrant(self.arc_segments_per_quadrant)
+                        .offset_path(&subpath)?;
+                    loops.extend(outline.iter());
+                    continue;
+                }
+                // The winding check keeps the closed subpaths in their original order.
+                _ => closed.next().unwrap_or(subpath),
+            };
+
+            let shrinking = subpath.signed_area(0.01) * self.offset_distance < 0.0;
+            if let (true, Some((min, max))) = (shrinking, subpath.report(0.01).bounds)
+                && 2.0 * self.offset_distance.abs() >= (max.0 - min.0).min(max.1 - min.1)
+            {
+                collapsed = true;
+                continue;
+            }
 
-        let fitted_curve =
-            fit_curve::<Curve<Coord2>>(&offset_points, 1.0).ok_or(PathError::FitCurve)?;
+            let source = source_curves(&subpath);
+            // A lone point has no curves to offset, nor corners to preserve.
+            if source.is_empty() {
+                continue;
+            }
+            let fitted_curve = if let Some(join_style) = self.join_style {
+                self.fit_preserving_corners(&source, join_style)?
+            } else {
+                let offset_points = offset_curves(&source, self.offset_distance)
+                    .iter()
+                    .flat_map(|curve| self.sample_curve(curve))
+                    .collect::<Vec<_>>();
 
+                fit_curve::<Curve<Coord2>>(&offset_points, self.fit_tolerance)
+                    .ok_or(PathError::FitCurve)?
+            };
+
+            loops.extend(self.clean(fitted_curve)?.iter().map(Path::from));
+        }
+
+        if collapsed && loops.is_empty() {
+            return Err(PathError::EmptyResult);
+        }
+        Ok(self.collect_loops(loops).with_scalars_from(path))
+    }
+}
+
+impl FloCurvesOffset {
+    /// Removes the interior points of the fitted offset curves of one subpath, producing
+    /// its final loops.
+    fn clean(&self, fitted_curve: Vec<Curve<Coord2>>) -> Result<Vec<SimpleBezierPath>> {
         let offset_toolpath = SimpleBezierPath::from_connected_curves(
             fitted_curve
                 .into_iter()
                 .filter(|curve| !curve_is_tiny(curve)),
         );
 
-        let clean_offset_toolpath: SimpleBezierPath =
-            path_remove_interior_points(&vec![offset_toolpath], 0.01)
+        let clean_offset_toolpaths: Vec<SimpleBezierPath> =
+            path_remove_interior_points(&vec![offset_toolpath], self.clean_tolerance);
+
+        if clean_offset_toolpaths.is_empty() {
+            return Err(PathError::CleanPath);
+        }
+        Ok(clean_offset_toolpaths)
+    }
+
+    /// Gathers the loops of every subpath into a path, largest first if `sort_by_area` is set.
+    fn collect_loops(&self, mut loops: Vec<Path>) -> Path {
+        if self.sort_by_area {
+            let mut sized = loops
                 .into_iter()
-                .next()
-                .ok_or(PathError::CleanPath)?;
+                .map(|toolpath| (toolpath.approximate_signed_area(0.01).abs(), toolpath))
+                .collect::<Vec<_>>();
+            sized.sort_by(|(area_a, _), (area_b, _)| area_b.total_cmp(area_a));
+
+            loops = sized.into_iter().map(|(_, toolpath)| toolpath).collect();
+        }
+
+        // Keep every loop: an offset can legitimately split a shape into separate regions.
+        loops.into_iter().collect()
+    }
+
+    /// Samples a Bezier curve and returns a set of representative points.
+    ///
+    /// This walks along the curve every `sample_distance`, within `sample_max_error`, and
+    /// samples the midpoint of each section to generate a set of points that approximate
+    /// the curve.
+    fn sample_curve(&self, curve: &Curve<Coord2>) -> Vec<Coord2> {
+        // Take the midpoint (t=0.5) of each sampled section as the final sample point.
+        walk_curve_evenly(curve, self.sample_distance, self.sample_max_error)
+            .map(|section| section.point_at_pos(0.5))
+            .collect::<Vec<_>>()
+    }
+
+    /// Refits the offset samples piecewise between the join vertices of sharp corners.
+    fn fit_preserving_corners(
+        &self,
+        source: &[Curve<Coord2>],
+        join_style: JoinStyle,
+    ) -> Result<Vec<Curve<Coord2>>> {
+        let distance = self.offset_distance;
+        let count = source.len();
+        let closed = match (source.first(), source.last()) {
+            (Some(first), Some(last)) => first.start_point().is_near_to(&last.end_point(), 1e-3),
+            _ => false,
+        };
+
+        // The offset samples of each source curve.
+        let mut samples: Vec<Vec<Coord2>> = source
+            .iter()
+            .map(|curve| {
+                offset(curve, -distance, -distance)
+                    .iter()
+                    .filter(|curve| !curve_is_tiny(*curve))
+                    .flat_map(|curve| self.sample_curve(curve))
+                    .collect()
+            })
+            .collect();
+
+        // The join at the end of each source curve that ends in a sharp corner, as the
+        // vertices where it is entered and left, and the corner itself. The vertices
+        // coincide for a miter.
+        let mut anchors: Vec<Option<(Coord2, Coord2, Coord2)>> = vec![None; count];
+        for index in 0..count {
+            let next = (index + 1) % count;
+            if next == 0 && !closed {
+                break;
+            }
+            let (incoming, outgoing) = (end_tangent(&source[index]), start_tangent(&source[next]));
+            let corner = source[index].end_point();
+            let Some((entry, exit)) = join(
+                corner,
+                incoming,
+                outgoing,
+                distance,
+                join_style,
+                self.miter_limit,
+            ) else {
+                continue;
+            };
+
+            // At a concave corner the offset curves overlap, so the samples past the join
+            // vertex are trimmed away.
+            if (incoming.x() * outgoing.y() - incoming.y() * outgoing.x()) * distance < 0.0 {
+                samples[index].retain(|sample| (*sample - entry).dot(&incoming) < 0.0);
+                samples[next].retain(|sample| (*sample - exit).dot(&outgoing) > 0.0);
+            }
+            anchors[index] = Some((entry, exit, corner));
+        }
+
+        // Start at a join vertex if there is one, so that every piece is anchored at both ends.
+        let start = match anchors.iter().position(Option::is_some) {
+            Some(index) if closed => (index + 1) % count,
+            _ => 0,
+        };
+
+        let mut fitted = Vec::new();
+        let mut points: Vec<Coord2> = anchors[(start + count - 1) % count]
+            .filter(|_| closed)
+            .map(|(_, exit, _)| exit)
+            .into_iter()
+            .collect();
+        for index in (start..count).chain(0..start) {
+            points.extend(&samples[index]);
+            if let Some((entry, exit, corner)) = anchors[index] {
+                points.push(entry);
+                fitted.extend(
+                    fit_curve::<Curve<Coord2>>(&points, self.fit_tolerance)
+                        .ok_or(PathError::FitCurve)?,
+                );
+                if entry != exit {
+                    match join_style {
+                        JoinStyle::Round => fitted.extend(round_join(
+                            corner,
+                            entry,
+                            exit,
+                            self.arc_segments_per_quadrant,
+                        )),
+                        _ => fitted.push(line_curve(entry, exit)),
+                    }
+                }
+                points = vec![exit];
+            }
+        }
+        if points.len() > 1 {
+            fitted.extend(
+                fit_curve::<Curve<Coord2>>(&points, self.fit_tolerance)
+                    .ok_or(PathError::FitCurve)?,
+            );
+        }
 
-        Ok(Path::from(&clean_offset_toolpath))
+        if fitted.is_empty() {
+            return Err(PathError::FitCurve);
+        }
+        Ok(fitted)
     }
 }
 
-/// Samples a Bezier curve and returns a set of representative points.
-///
-/// This function walks along the curve at a fixed distance and samples the midpoint
-/// of each segment to generate a set of points that approximate the curve.
-///
-/// # Arguments
-///
-/// * `curve` - The Bezier curve to sample.
+/// Converts `subpath`, a single subpath, into the `flo_curves` curves to be offset.
+fn source_curves(subpath: &Path) -> Vec<Curve<Coord2>> {
+    // Degenerate curves, such as zero-length closing lines, have no direction to offset in.
+    SimpleBezierPath::from(subpath)
+        .to_curves::<Curve<Coord2>>()
+        .into_iter()
+        .filter(|curve| !curve_is_tiny(curve))
+        .collect()
+}
+
+/// Offsets each of the `source` curves by `distance`.
+fn offset_curves(source: &[Curve<Coord2>], distance: f64) -> Vec<Curve<Coord2>> {
+    source
+        .iter()
+        .flat_map(|curve| offset(curve, -distance, -distance))
+        .filter(|curve| !curve_is_tiny(curve))
+        .collect()
+}
+
+/// Returns a straight line from `start` to `end` as a cubic curve.
+fn line_curve(start: Coord2, end: Coord2) -> Curve<Coord2> {
+    let step = (end - start) * (1.0 / 3.0);
+    Curve::from_points(start, (start + step, start + step * 2.0), end)
+}
+
+/// Returns the unit tangent of `curve` at its start point.
+fn start_tangent(curve: &Curve<Coord2>) -> Coord2 {
+    let (ctrl1, ctrl2) = curve.control_points();
+    let start = curve.start_point();
+    [ctrl1, ctrl2, curve.end_point()]
+        .into_iter()
+        .map(|point| point - start)
+        .find(|direction| direction.magnitude() > 1e-9)
+        .unwrap_or_else(Coord2::origin)
+        .to_unit_vector()
+}
+
+/// Returns the unit tangent of `curve` at its end point.
+fn end_tangent(curve: &Curve<Coord2>) -> Coord2 {
+    let (ctrl1, ctrl2) = curve.control_points();
+    let end = curve.end_point();
+    [ctrl2, ctrl1, curve.start_point()]
+        .into_iter()
+        .map(|point| end - point)
+        .find(|direction| direction.magnitude() > 1e-9)
+        .unwrap_or_else(Coord2::origin)
+        .to_unit_vector()
+}
+
+/// Computes the join of the offset lines on either side of a sharp corner.
 ///
-/// # Returns
+/// Returns the vertices where the join is entered and left: the intersection of the offset
+/// lines twice for a miter, or the ends of the offset lines otherwise, to be connected by
+/// a line or an arc. A convex corner is mitered with [`JoinStyle::Miter`] unless its miter
+/// would lie more than `limit × distance` from the corner. A concave corner is always cut
+/// at the intersection, unless that lies beyond the miter limit, in which case `None` is
+/// returned. `None` is also returned if the corner is smooth.
+fn join(
+    corner: Coord2,
+    incoming: Coord2,
+    outgoing: Coord2,
+    distance: f64,
+    join_style: JoinStyle,
+    miter_limit: f64,
+) -> Option<(Coord2, Coord2)> {
+    let cos = incoming.dot(&outgoing);
+    if cos.is_nan() || cos > 1.0 - 1e-6 {
+        return None;
+    }
+
+    let convex = (incoming.x() * outgoing.y() - incoming.y() * outgoing.x()) * distance > 0.0;
+    let miter_limit = match join_style {
+        JoinStyle::Miter { limit } => limit,
+        _ if convex => 0.0,
+        _ => miter_limit,
+    };
+
+    // A positive distance offsets to the right of the direction of travel.
+    let normal = |tangent: Coord2| Coord2(tangent.y(), -tangent.x());
+    if (2.0 / (1.0 + cos)).sqrt() <= miter_limit {
+        let miter = corner + (normal(incoming) + normal(outgoing)) * (distance / (1.0 + cos));
+        return Some((miter, miter));
+    }
+
+    convex.then(|| {
+        (
+            corner + normal(incoming) * distance,
+            corner + normal(outgoing) * distance,
+        )
+    })
+}
+
+/// Returns the circular arc around `center` from `start` to `end` as cubic curves.
 ///
-/// A `Vec<Coord2>` containing the sampled points.
-fn sample_curve(curve: &Curve<Coord2>) -> Vec<Coord2> {
-    let max_error = 0.01;
-    let distance = 0.1;
-
-    // Take the midpoint (t=0.5) of each sampled section as the final sample point.
-    walk_curve_evenly(curve, distance, max_error)
-        .map(|section| section.point_at_pos(0.5))
-        .collect::<Vec<_>>()
+/// The arc takes the shorter way around, split into `arc_segments_per_quadrant` pieces per
+/// quarter turn so that each is closely approximated by a cubic curve.
+fn round_join(
+    center: Coord2,
+    start: Coord2,
+    end: Coord2,
+    arc_segments_per_quadrant: usize,
+) -> Vec<Curve<Coord2>> {
+    let (from, to) = (start - center, end - center);
+    let sweep = (from.x() * to.y() - from.y() * to.x()).atan2(from.dot(&to));
+    let point = |coord: Coord2| lyon::geom::point(coord.x(), coord.y());
+    let coord = |point: lyon::geom::Point<f64>| Coord2(point.x, point.y);
+
+    arc_curves(
+        point(center),
+        point(start),
+        point(end),
+        sweep,
+        arc_segments_per_quadrant,
+    )
+    .into_iter()
+    .map(|curve| {
+        Curve::from_points(
+            coord(curve.from),
+            (coord(curve.ctrl1), coord(curve.ctrl2)),
+            coord(curve.to),
+        )
+    })
+    .collect()
 }
@@ -0,0 +1,118 @@
+//! Grows an offset step by step from its previous result.
+//!
+//! Animating an offset whose distance changes a little every frame can build each frame from
+//! the last one. [`IncrementalOffset`] keeps the previous result and offsets it by the change
+//! in distance, going back to the source every few steps so that the error of the backend
+//! does not pile up without bound.
+
+use crate::{error::Result, offset::Backend, path::Path};
+
+/// An offset of a source path that is moved by a small distance at a time.
+///
+/// Each [`IncrementalOffset::step`] offsets the current result by `delta` rather than the
+/// source by the total distance. Every `resync_every` steps, the source is offset directly
+/// by the total distance instead, which discards the error accumulated since the last
+/// resync.
+///
+/// # Error accumulation
+///
+/// Each step carries the full approximation error of the backend, and the error of every
+/// step is offset along with the geometry by the steps that follow. The deviation from a
+/// direct offset by the total distance therefore grows with the number of steps since the
+/// last resync, not with the total distance. The `flo_curves` backend refits every step, so
+/// ten steps typically deviate several times more than one direct offset. Features that the
+/// offset removes, such as a corner rounded by growing or a region that vanishes when
+/// shrinking, do not come back when stepping the other way until the next resync.
+///
+/// # Example
+///
+/// ```
+/// use path_offset::offset::{Backend, incremental::IncrementalOffset};
+/// use path_offset::path::Path;
+/// use std::str::FromStr;
+///
+/// let circle = Path::from_str("M10,0 A10,10 0 1 1 -10,0 A10,10 0 1 1 10,0 Z").unwrap();
+/// let worst = |path: &Path| {
+///     path.offset_error_profile(&circle, 1.0, 100, 0.01)
+///         .into_iter()
+///         .fold(0.0, |worst: f64, deviation| worst.max(deviation.abs()))
+/// };
+///
+/// // Ten steps of 0.1 stay close to a single offset by 1.0.
+/// let mut animated = IncrementalOffset::new(circle.clone(), Backend::FloCurves, 0);
+/// for _ in 0..10 {
+///     animated.step(0.1).unwrap();
+/// }
+/// assert!((animated.distance() - 1.0).abs() < 1e-9);
+/// assert!(worst(animated.path()) < 0.5);
+///
+/// // Resyncing every fifth step makes the tenth an offset of the source.
+/// let direct = path_offset::offset(&circle, 1.0, Backend::FloCurves).unwrap();
+/// assert!(worst(&direct) < 0.1);
+/// let mut resynced = IncrementalOffset::new(circle.clone(), Backend::FloCurves, 5);
+/// for _ in 0..10 {
+///     resynced.step(0.1).unwrap();
+/// }
+/// assert_eq!(resynced.path().to_string(), direct.to_string());
+/// ```
+#[derive(Debug, Clone)]
+pub struct IncrementalOffset {
+    source: Path,
+    backend: Backend,
+    resync_every: usize,
+    distance: f64,
+    /// The number of steps since the current result was offset from the source.
+    steps: usize,
+    current: Path,
+}
+
+impl IncrementalOffset {
+    /// Starts an incremental offset of `source` at a distance of `0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The path being offset.
+    /// * `backend` - The offsetting implementation to use.
+    /// * `resync_every` - The number of steps after which the source is offset directly, or
+    ///   `0` to always offset the previous result.
+    pub fn new(source: Path, backend: Backend, resync_every: usize) -> Self {
+        IncrementalOffset {
+            current: source.clone(),
+            source,
+            backend,
+            resync_every,
+            distance: 0.0,
+            steps: 0,
+        }
+    }
+
+    /// Moves the offset by a further `delta`, following the sign convention of
+    /// [`Offset`](super::Offset).
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the offset at the new total distance, or the error raised while
+    /// offsetting. On error, the offset is left as it was.
+    pub fn step(&mut self, delta: f64) -> Result<&Path> {
+        let distance = self.distance + delta;
+        let resync = self.resync_every > 0 && self.steps + 1 >= self.resync_every;
+        self.current = if resync {
+            self.backend.offset_path(&self.source, distance)?
+        } else {
+            self.backend.offset_path(&self.current, delta)?
+        };
+        self.steps = if resync { 0 } else { self.steps + 1 };
+        self.distance = distance;
+        Ok(&self.current)
+    }
+
+    /// Returns the offset at the current total distance.
+    pub fn path(&self) -> &Path {
+        &self.current
+    }
+
+    /// Returns the total distance of the current offset from the source.
+    pub fn distance(&self) -> f64 {
+        self.distance
+    }
+}
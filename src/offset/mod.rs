@@ -1,17 +1,56 @@
 //! Defines the `Offset` trait for path offsetting.
 //!
 //! This module provides the central `Offset` trait, which defines the contract for path offsetting algorithms.
-//! It also includes modules for different offsetting implementations, such as `cavalier_contours` and `flo_curves`.
+//! It also includes modules for different offsetting implementations, such as `cavalier_contours` and `flo_curves`,
+//! and high-level helpers that select an implementation through the [`Backend`] enum.
 
+pub mod cache;
 pub mod cavalier_contours;
+pub mod diagnostics;
 pub mod flo_curves;
+pub mod incremental;
 
-use crate::{error::Result, path::Path};
+use crate::{
+    error::{PathError, Result},
+    path::{Path, contour::ContourKind},
+};
 
 /// A trait for types that can offset a path.
 ///
 /// This trait provides a generic interface for path offsetting algorithms.
 /// Implementors of this trait are expected to provide an implementation for the `offset_path` method.
+///
+/// # Sign convention
+///
+/// Every implementation offsets a closed subpath to the right of its direction of travel
+/// for a positive distance, and to the left for a negative one. A positive distance thus
+/// grows [`Orientation::CounterClockwise`](crate::path::contour::Orientation) subpaths,
+/// those with a positive [`signed_area`](Path::signed_area), and shrinks clockwise ones,
+/// whichever backend is used.
+///
+/// # Example
+///
+/// ```
+/// use path_offset::offset::{
+///     Offset, cavalier_contours::CavalierContours, flo_curves::FloCurvesOffset,
+/// };
+/// use path_offset::path::{Path, contour::Orientation};
+/// use std::str::FromStr;
+///
+/// let square = Path::from_str("M0,0 L10,0 L10,10 L0,10 Z").unwrap();
+/// assert_eq!(square.orientation(), Some(Orientation::CounterClockwise));
+///
+/// let backends: [Box<dyn Offset>; 2] = [
+///     Box::new(CavalierContours::new(1.0)),
+///     Box::new(FloCurvesOffset::new(1.0)),
+/// ];
+/// for backend in &backends {
+///     let grown = backend.offset_path(&square).unwrap();
+///     let shrunk = backend.offset_path(&square.reverse()).unwrap();
+///     assert!(grown.signed_area(0.01).abs() > 110.0);
+///     assert!(shrunk.signed_area(0.01).abs() < 90.0);
+/// }
+/// ```
 pub trait Offset {
     /// Offsets the given path.
     ///
@@ -22,5 +61,751 @@ pub trait Offset {
     /// # Returns
     ///
     /// A `Result` containing the offset `Path` or an error.
-    fn offset_path(&self) -> Result<Path>;
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PathError::EmptyResult`] if the offset leaves nothing of the path, as when
+    /// shrinking a shape by more than its inradius.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::error::PathError;
+    /// use path_offset::offset::{
+    ///     Offset, cavalier_contours::CavalierContours, flo_curves::FloCurvesOffset,
+    /// };
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// let square = Path::from_str("M0,0 L1,0 L1,1 L0,1 Z").unwrap();
+    ///
+    /// let result = CavalierContours::new(-5.0).offset_path(&square);
+    /// assert!(matches!(result, Err(PathError::EmptyResult)));
+    ///
+    /// let result = FloCurvesOffset::new(-5.0).offset_path(&square);
+    /// assert!(matches!(result, Err(PathError::EmptyResult)));
+    /// ```
+    fn offset_path(&self, path: &Path) -> Result<Path>;
+}
+
+/// How the offset segments on either side of a convex corner are joined.
+///
+/// At a convex corner the offset segments separate, leaving a gap that has to be bridged.
+/// The styles follow SVG's `stroke-linejoin`. At concave corners the offset segments
+/// overlap instead, and are trimmed back to their intersection whatever the style.
+///
+/// # Example
+///
+/// ```
+/// use path_offset::offset::{JoinStyle, Offset, cavalier_contours::CavalierContours};
+/// use path_offset::path::Path;
+/// use std::str::FromStr;
+///
+/// let path = Path::from_str("M0,0 L10,0 L10,10 L0,10 Z").unwrap();
+/// let length = |join_style: JoinStyle| {
+///     let offset_path = CavalierContours::new(1.0)
+///         .with_join_style(join_style)
+///         .offset_path(&path)
+///         .unwrap();
+///     offset_path.report(0.01).length
+/// };
+///
+/// // Each join cuts the corners of the outline shorter than the previous one.
+/// assert!((length(JoinStyle::Miter { limit: 4.0 }) - 48.0).abs() < 0.05);
+/// assert!((length(JoinStyle::Round) - (40.0 + 2.0 * std::f64::consts::PI)).abs() < 0.05);
+/// assert!((length(JoinStyle::Bevel) - (40.0 + 4.0 * 2f64.sqrt())).abs() < 0.05);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum JoinStyle {
+    /// Extends the offset segments until they meet. If their intersection lies more than
+    /// `limit × distance` from the source corner, the corner is bevelled instead, as with
+    /// SVG's `stroke-miterlimit`.
+    Miter {
+        /// The maximum ratio between the miter length and the offset distance.
+        limit: f64,
+    },
+    /// Bridges the gap with a circular arc of radius `distance` around the source corner.
+    Round,
+    /// Bridges the gap with a straight line between the ends of the offset segments.
+    Bevel,
+}
+
+/// How the ends of an open subpath are capped when it is outlined.
+///
+/// The styles follow SVG's `stroke-linecap`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CapStyle {
+    /// Ends the outline flush with the ends of the subpath.
+    Butt,
+    /// Rounds the outline around each end with a half circle of radius `distance`.
+    Round,
+    /// Extends the outline past each end by `distance`, squaring it off.
+    Square,
+}
+
+/// Selects the offsetting implementation used by the high-level helpers in this module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    /// Offsets with [`FloCurvesOffset`](self::flo_curves::FloCurvesOffset).
+    FloCurves,
+    /// Offsets with [`CavalierContours`](self::cavalier_contours::CavalierContours).
+    CavalierContours,
+}
+
+impl Backend {
+    /// Offsets `path` by `distance` using this backend.
+    fn offset_path(self, path: &Path, distance: f64) -> Result<Path> {
+        match self {
+            Backend::FloCurves => {
+                self::flo_curves::FloCurvesOffset::new(distance).offset_path(path)
+            }
+            Backend::CavalierContours => {
+                self::cavalier_contours::CavalierContours::new(distance).offset_path(path)
+            }
+        }
+    }
+}
+
+/// Offsets a path by `distance` with the selected backend.
+///
+/// This is the simplest way to offset a path: it dispatches to
+/// [`FloCurvesOffset`](self::flo_curves::FloCurvesOffset) or
+/// [`CavalierContours`](self::cavalier_contours::CavalierContours) with their default
+/// settings, so switching backends is a matter of changing one argument. Construct the
+/// implementations directly to configure them. It is also available at the crate root, as
+/// `path_offset::offset`.
+///
+/// Both backends offset every subpath, but their results are not identical:
+/// `FloCurvesOffset` approximates the offset with fitted curves, rounds its corners
+/// slightly short, and can leave tiny debris loops where an inward offset pinches, which
+/// [`Path::remove_small_subpaths`] discards.
+///
+/// # Arguments
+///
+/// * `path` - A reference to the `Path` to be offset.
+/// * `distance` - The distance by which to offset the path, following the sign convention
+///   of [`Offset`].
+/// * `backend` - The offsetting implementation to use.
+///
+/// # Returns
+///
+/// A `Result` containing the offset `Path` or an error if offsetting fails.
+///
+/// # Example
+///
+/// ```
+/// use path_offset::path::Path;
+/// use path_offset::{Backend, offset};
+/// use std::str::FromStr;
+///
+/// let square = Path::from_str("M0,0 L10,0 L10,10 L0,10 Z").unwrap();
+///
+/// for backend in [Backend::FloCurves, Backend::CavalierContours] {
+///     let grown = offset(&square, 1.0, backend).unwrap();
+///     let bounds = grown.report(0.01).bounds.unwrap();
+///     assert!((bounds.0.0 + 1.0).abs() < 0.5 && (bounds.1.0 - 11.0).abs() < 0.5);
+/// }
+///
+/// // Separate shapes are each offset.
+/// let squares = Path::from_str("M0,0 L10,0 L10,10 L0,10 Z M20,0 L30,0 L30,10 L20,10 Z").unwrap();
+/// for backend in [Backend::FloCurves, Backend::CavalierContours] {
+///     let shrunk = offset(&squares, -1.0, backend).unwrap();
+///     assert_eq!(shrunk.remove_small_subpaths(1.0, 0.01).subpath_count(), 2);
+/// }
+/// ```
+pub fn offset(path: &Path, distance: f64, backend: Backend) -> Result<Path> {
+    backend.offset_path(path, distance)
+}
+
+/// Offsets a path and cleans up the result in a single call.
+///
+/// The pipeline runs the following stages in order:
+///
+/// 1. **Offset**: `path` is offset by `distance` with the selected `backend`.
+/// 2. **Remove interior points**: any remaining self-intersections are resolved so that only
+///    the outline is kept, treating intersections closer than `clean_tolerance` as one (see
+///    [`Path::remove_interior_points`]).
+/// 3. **Remove tiny subpaths**: closed loops enclosing less than `min_area` are discarded as
+///    offsetting debris (see [`Path::drop_small_features`]).
+/// 4. **Simplify**: the outline is flattened and reduced with Douglas–Peucker so that no vertex
+///    deviates more than `simplify_tolerance` from it (see [`Path::simplify`]).
+///
+/// # Arguments
+///
+/// * `path` - A reference to the `Path` to be offset.
+/// * `distance` - The distance by which to offset the path.
+/// * `backend` - The offsetting implementation to use.
+/// * `clean_tolerance` - The accuracy used when removing interior points. `0.01` suits most
+///   paths; see [`flo_curves::FloCurvesOffset::with_clean_tolerance`] for when to change it.
+/// * `min_area` - The area below which a loop of the offset is discarded as debris. The area
+///   of a square with a side of `distance` removes the small loops `flo_curves` leaves at
+///   concave corners; where a narrow part of the shape collapses, the debris can be larger.
+/// * `simplify_tolerance` - The maximum deviation allowed by the simplification stage.
+///
+/// # Returns
+///
+/// A `Result` containing the cleaned offset `Path` or an error if any stage fails.
+///
+/// # Example
+///
+/// ```
+/// use path_offset::offset::{Backend, Offset, flo_curves::FloCurvesOffset, offset_clean};
+/// use path_offset::path::Path;
+/// use std::str::FromStr;
+///
+/// // A dumbbell: two 10x10 lobes joined by a 2-unit-wide handle.
+/// let path = Path::from_str(
+///     "M0,0 L10,0 L10,4 L20,4 L20,0 L30,0 L30,10 L20,10 L20,6 L10,6 L10,10 L0,10 Z",
+/// )
+/// .unwrap();
+///
+/// // Shrinking the dumbbell splits it into its two lobes, with debris around them.
+/// let raw = FloCurvesOffset::new(-1.5).offset_path(&path).unwrap();
+/// assert!(raw.subpath_count() > 2);
+///
+/// // The collapsed handle leaves a loop of about 11 behind, larger than the lobe corners do.
+/// let clean = offset_clean(&path, -1.5, Backend::FloCurves, 0.01, 20.0, 0.1).unwrap();
+/// assert_eq!(clean.subpath_count(), 2);
+/// assert!(clean.self_intersections(0.01).is_empty());
+/// assert!(clean.segment_count() < raw.segment_count());
+/// ```
+pub fn offset_clean(
+    path: &Path,
+    distance: f64,
+    backend: Backend,
+    clean_tolerance: f64,
+    min_area: f64,
+    simplify_tolerance: f64,
+) -> Result<Path> {
+    let offset_path = backend.offset_path(path, distance)?;
+    let outline = offset_path.remove_interior_points(clean_tolerance)?;
+    let without_debris = outline.drop_small_features(min_area);
+
+    Ok(without_debris.simplify(simplify_tolerance))
+}
+
+/// Offsets only the subpaths of `path` that match `predicate`.
+///
+/// Each subpath for which `predicate` returns `true` is offset on its own by `distance`;
+/// every other subpath is passed through unchanged. The results are recombined into a
+/// single path in the original subpath order. This makes it possible to, for example,
+/// offset the shells of a shape while leaving its holes as they are.
+///
+/// # Arguments
+///
+/// * `path` - A reference to the `Path` whose subpaths should be offset.
+/// * `distance` - The distance by which to offset the matching subpaths.
+/// * `backend` - The offsetting implementation to use.
+/// * `predicate` - Decides which subpaths are offset.
+///
+/// # Returns
+///
+/// A `Result` containing the recombined `Path` or the first error raised while offsetting.
+///
+/// # Example
+///
+/// ```
+/// use path_offset::offset::{Backend, offset_where};
+/// use path_offset::path::Path;
+/// use std::str::FromStr;
+///
+/// let path = Path::from_str("M0,0 L10,0 L10,10 L0,10 Z M20,0 L30,0").unwrap();
+/// let result = offset_where(&path, 1.0, Backend::FloCurves, |subpath| subpath.is_closed()).unwrap();
+///
+/// let subpaths: Vec<Path> = result.iter().collect();
+/// assert_eq!(subpaths.len(), 2);
+/// assert_ne!(subpaths[0].to_string(), "M0,0L10,0L10,10L0,10Z");
+/// assert_eq!(subpaths[1].to_string(), "M20,0L30,0");
+/// ```
+pub fn offset_where(
+    path: &Path,
+    distance: f64,
+    backend: Backend,
+    predicate: impl Fn(&Path) -> bool,
+) -> Result<Path> {
+    offset_subpaths(path, |subpath| {
+        if predicate(&subpath) {
+            backend.offset_path(&subpath, distance)
+        } else {
+            Ok(subpath)
+        }
+    })
+}
+
+/// Offsets a shape with holes, growing its shells and shrinking its holes.
+///
+/// The backends offset every subpath to the same side of its direction of travel, so a hole
+/// wound the same way as its shell would grow along with it instead of shrinking. Here each
+/// subpath is classified with [`Path::classify_contours`] and offset on its own, in the
+/// direction that matches its role: a positive `distance` grows every shell and shrinks
+/// every hole, adding material around the whole shape, whatever their winding. A negative
+/// `distance` removes material instead. The results are recombined in subpath order.
+///
+/// # Arguments
+///
+/// * `path` - A reference to the `Path` to be offset.
+/// * `distance` - The distance by which to grow the shape.
+/// * `backend` - The offsetting implementation to use.
+///
+/// # Returns
+///
+/// A `Result` containing the offset `Path` or the first error raised while offsetting.
+///
+/// # Example
+///
+/// ```
+/// use path_offset::offset::{Backend, offset_with_holes};
+/// use path_offset::path::{Path, point::Point};
+/// use std::str::FromStr;
+///
+/// // An annulus whose hole winds the same way as its shell.
+/// let annulus = Path::from_str("M0,0 L10,0 L10,10 L0,10 Z M3,3 L7,3 L7,7 L3,7 Z").unwrap();
+/// let grown = offset_with_holes(&annulus, 1.0, Backend::CavalierContours).unwrap();
+///
+/// let bounds: Vec<_> = grown.iter().map(|subpath| subpath.bounding_box().unwrap()).collect();
+/// assert_eq!(bounds[0], (Point(-1.0, -1.0), Point(11.0, 11.0)));
+/// assert_eq!(bounds[1], (Point(4.0, 4.0), Point(6.0, 6.0)));
+/// ```
+pub fn offset_with_holes(path: &Path, distance: f64, backend: Backend) -> Result<Path> {
+    let mut kinds = path.classify_contours().into_iter();
+    offset_subpaths(path, |subpath| {
+        // A positive distance grows subpaths with a positive signed area.
+        let growing = match kinds.next() {
+            Some(ContourKind::Hole) => -distance,
+            _ => distance,
+        };
+        let direction = if subpath.signed_area(0.01) < 0.0 {
+            -1.0
+        } else {
+            1.0
+        };
+        backend.offset_path(&subpath, growing * direction)
+    })
+}
+
+/// Offsets each subpath of `path` on its own and recombines the results in subpath order.
+///
+/// A subpath whose offset collapses is dropped. [`PathError::EmptyResult`] is only returned
+/// if nothing is left of any subpath.
+fn offset_subpaths(path: &Path, mut offset: impl FnMut(Path) -> Result<Path>) -> Result<Path> {
+    let mut results = Vec::new();
+    for subpath in path.iter() {
+        match offset(subpath) {
+            Ok(result) => results.push(result),
+            Err(PathError::EmptyResult) => {}
+            Err(error) => return Err(error),
+        }
+    }
+
+    let result: Path = results.into_iter().collect();
+    if result.subpath_count() == 0 && path.subpath_count() > 0 {
+        return Err(PathError::EmptyResult);
+    }
+    Ok(result)
+}
+
+/// Offsets a path both outward and inward by the same distance.
+///
+/// This returns the pair of offsets at `+|distance|` and `-|distance|`, such as the two
+/// edges of a band of constant width around a shape. Backends do not agree on the winding
+/// of their results, so each offset is reversed where needed to wind the same way as the
+/// source overall: combining them into one path gives a closed band under the even-odd fill
+/// rule. For the nonzero rule, reverse the inward offset first with [`Path::reverse`].
+///
+/// # Arguments
+///
+/// * `path` - A reference to the `Path` to be offset.
+/// * `distance` - The distance between the source and each of the offsets. Its sign is
+///   ignored.
+/// * `backend` - The offsetting implementation to use.
+///
+/// # Returns
+///
+/// A `Result` containing the outward and the inward offsets, in that order, or the first
+/// error raised while offsetting.
+///
+/// # Example
+///
+/// ```
+/// use path_offset::offset::{Backend, offset_both};
+/// use path_offset::path::{Path, point::Point};
+/// use std::str::FromStr;
+///
+/// let square = Path::from_str("M0,0 L10,0 L10,10 L0,10 Z").unwrap();
+/// let (outward, inward) = offset_both(&square, 1.0, Backend::CavalierContours).unwrap();
+///
+/// assert_eq!(outward.bounding_box(), Some((Point(-1.0, -1.0), Point(11.0, 11.0))));
+/// assert_eq!(inward.bounding_box(), Some((Point(1.0, 1.0), Point(9.0, 9.0))));
+///
+/// // Both offsets wind like the source, whatever the backend.
+/// for backend in [Backend::CavalierContours, Backend::FloCurves] {
+///     let (outward, inward) = offset_both(&square, 1.0, backend).unwrap();
+///     assert!(outward.signed_area(0.01) > 0.0 && inward.signed_area(0.01) > 0.0);
+/// }
+///
+/// // A band two units wide, filled with either rule once the inner edge is reversed.
+/// let band: Path = outward.iter().chain(inward.reverse().iter()).collect();
+/// let area = 144.0 - (4.0 - std::f64::consts::PI) - 64.0;
+/// assert!((band.signed_area(0.001) - area).abs() < 0.01);
+///
+/// // Holes are offset along with their shells: a 20x20 square with a 10x10 hole grows
+/// // outward and its hole shrinks, and the other way around for the inward offset.
+/// let annulus = Path::from_str("M0,0 L20,0 L20,20 L0,20 Z M5,5 L5,15 L15,15 L15,5 Z").unwrap();
+/// let corners = 4.0 - std::f64::consts::PI;
+/// for backend in [Backend::CavalierContours, Backend::FloCurves] {
+///     let (outward, inward) = offset_both(&annulus, 1.0, backend).unwrap();
+///     let (outward, inward) = (
+///         outward.remove_small_subpaths(10.0, 0.01),
+///         inward.remove_small_subpaths(10.0, 0.01),
+///     );
+///     assert_eq!((outward.subpath_count(), inward.subpath_count()), (2, 2));
+///     assert!((outward.signed_area(0.01) - (484.0 - corners - 64.0)).abs() < 5.0);
+///     assert!((inward.signed_area(0.01) - (324.0 - (144.0 - corners))).abs() < 5.0);
+/// }
+/// ```
+pub fn offset_both(path: &Path, distance: f64, backend: Backend) -> Result<(Path, Path)> {
+    let distance = distance.abs();
+    let positive = path.signed_area(0.01) >= 0.0;
+    let oriented = |offset: Path| {
+        // The loops of a `flo_curves` offset do not reliably keep the winding of their
+        // source subpaths, so holes are first made to wind against their shells.
+        let offset = offset.normalize_winding();
+        if (offset.signed_area(0.01) >= 0.0) == positive {
+            offset
+        } else {
+            offset.reverse()
+        }
+    };
+
+    Ok((
+        oriented(backend.offset_path(path, distance)?),
+        oriented(backend.offset_path(path, -distance)?),
+    ))
+}
+
+/// Offsets a shape inward repeatedly, producing concentric rings as used for pocketing.
+///
+/// The rings lie at `step`, `2 × step`, `3 × step`, and so on inside the shape, each offset
+/// directly from the source with [`offset_with_holes`] so errors do not accumulate from ring
+/// to ring. The series stops once a ring vanishes, after `max_count` rings if given, or as
+/// soon as a ring fails to enclose less area than the previous one, which guards against
+/// looping forever on a backend that cannot shrink the shape.
+///
+/// # Arguments
+///
+/// * `path` - A reference to the closed `Path` to be pocketed.
+/// * `step` - The distance between consecutive rings. Its sign is ignored, and a step of
+///   zero produces no rings.
+/// * `max_count` - The maximum number of rings, or `None` to continue until the shape
+///   vanishes.
+/// * `backend` - The offsetting implementation to use.
+///
+/// # Returns
+///
+/// A `Result` containing the rings from the outermost inward, each as its own `Path`, or
+/// the first error raised while offsetting.
+///
+/// # Example
+///
+/// ```
+/// use path_offset::offset::{Backend, offset_series};
+/// use path_offset::path::Path;
+/// use std::str::FromStr;
+///
+/// let square = Path::from_str("M0,0 L10,0 L10,10 L0,10 Z").unwrap();
+///
+/// // The fifth ring would collapse the square to its center.
+/// let rings = offset_series(&square, 1.0, None, Backend::CavalierContours).unwrap();
+/// let areas: Vec<f64> = rings.iter().map(|ring| ring.signed_area(0.01)).collect();
+/// assert_eq!(areas, [64.0, 36.0, 16.0, 4.0]);
+///
+/// let rings = offset_series(&square, 1.0, Some(2), Backend::CavalierContours).unwrap();
+/// assert_eq!(rings.len(), 2);
+/// ```
+pub fn offset_series(
+    path: &Path,
+    step: f64,
+    max_count: Option<usize>,
+    backend: Backend,
+) -> Result<Vec<Path>> {
+    let step = step.abs();
+    let mut rings = Vec::new();
+    if step == 0.0 {
+        return Ok(rings);
+    }
+
+    let mut area = path.signed_area(0.01).abs();
+    for count in 1.. {
+        if max_count.is_some_and(|max_count| count > max_count) {
+            break;
+        }
+        let ring = match offset_with_holes(path, -step * count as f64, backend) {
+            Ok(ring) => ring,
+            // The `flo_curves` backend can also fail to clean an offset that leaves next to
+            // nothing behind.
+            Err(PathError::EmptyResult | PathError::CleanPath) => break,
+            Err(error) => return Err(error),
+        };
+
+        let ring_area = ring.signed_area(0.01).abs();
+        if ring_area >= area {
+            break;
+        }
+        area = ring_area;
+        rings.push(ring);
+    }
+
+    Ok(rings)
+}
+
+/// Offsets a path by the radius a V-bit cuts at a given depth.
+///
+/// A V-bit with an included angle of `tool_angle_deg` cuts a groove whose half-width at
+/// `depth` is `depth · tan(tool_angle_deg / 2)`. The path is offset by that radius, so
+/// V-carving toolpaths can be generated from depths instead of distances. A 90° bit cuts
+/// exactly as wide as it is deep.
+///
+/// # Arguments
+///
+/// * `path` - A reference to the `Path` to be offset.
+/// * `tool_angle_deg` - The included angle of the V-bit, in degrees.
+/// * `depth` - The cutting depth. The offset follows its sign, like any other distance.
+/// * `backend` - The offsetting implementation to use.
+///
+/// # Returns
+///
+/// A `Result` containing the offset `Path` or an error if offsetting fails.
+///
+/// # Example
+///
+/// ```
+/// use path_offset::offset::{Backend, v_carve};
+/// use path_offset::path::Path;
+/// use std::str::FromStr;
+///
+/// let circle = Path::from_str("M10,0 A10,10 0 1 1 -10,0 A10,10 0 1 1 10,0 Z").unwrap();
+/// let carved = v_carve(&circle, 90.0, 2.0, Backend::FloCurves).unwrap();
+///
+/// // A 90° bit at a depth of 2 cuts with a radius of 2 · tan(45°) = 2.
+/// let profile = carved.offset_error_profile(&circle, 2.0, 100, 0.01);
+/// assert!(profile.iter().all(|deviation| deviation.abs() < 0.1));
+/// ```
+pub fn v_carve(path: &Path, tool_angle_deg: f64, depth: f64, backend: Backend) -> Result<Path> {
+    let radius = depth * (tool_angle_deg.to_radians() / 2.0).tan();
+    backend.offset_path(path, radius)
+}
+
+/// Finds the offset of `path` that encloses `target_area`.
+///
+/// Instead of a distance, the caller specifies the area the result must enclose, for example
+/// for a part that has to hold a given volume at a fixed depth. The distance is found by
+/// bisection: it is first bracketed by doubling a step in the direction that grows or shrinks
+/// the path as needed, then narrowed until the enclosed area is within `tolerance` of
+/// `target_area`.
+///
+/// The area is measured on the backend's actual output, so the distance found compensates
+/// for the backend's approximation rather than matching the analytic distance exactly.
+///
+/// # Arguments
+///
+/// * `path` - A reference to the closed `Path` to be offset.
+/// * `target_area` - The area the result should enclose.
+/// * `backend` - The offsetting implementation to use.
+/// * `tolerance` - The largest acceptable difference between the enclosed and the target area.
+///
+/// # Returns
+///
+/// A `Result` containing the offset `Path` and the distance that produced it, or
+/// [`PathError::Unsupported`](crate::error::PathError::Unsupported) if `path` encloses no
+/// area or no offset reaches the target.
+///
+/// # Example
+///
+/// ```
+/// use path_offset::offset::{Backend, offset_to_target_area};
+/// use path_offset::path::Path;
+/// use std::str::FromStr;
+///
+/// let square = Path::from_str("M0,0 L10,0 L10,10 L0,10 Z").unwrap();
+/// let (grown, distance) = offset_to_target_area(&square, 200.0, Backend::FloCurves, 0.5).unwrap();
+///
+/// // The enclosed area of the flattened result, by the shoelace formula.
+/// let area: f64 = grown
+///     .to_polygon_rings(0.01)
+///     .iter()
+///     .map(|ring| {
+///         let edges = ring.iter().zip(ring.iter().cycle().skip(1));
+///         edges.map(|(a, b)| a[0] * b[1] - b[0] * a[1]).sum::<f64>() / 2.0
+///     })
+///     .sum();
+/// assert!((area.abs() - 200.0).abs() < 0.5);
+/// assert!(distance > 0.0);
+///
+/// // Every subpath is offset, so two such squares reach twice the area at the same distance.
+/// let squares = Path::from_str("M0,0 L10,0 L10,10 L0,10 Z M20,0 L30,0 L30,10 L20,10 Z").unwrap();
+/// // 100 + 40d + πd² = 200 for each square.
+/// let (grown, distance) =
+///     offset_to_target_area(&squares, 400.0, Backend::CavalierContours, 0.5).unwrap();
+/// assert_eq!(grown.subpath_count(), 2);
+/// assert!((distance - 2.141).abs() < 0.01);
+///
+/// // `flo_curves` cuts the rounded corners a little short, so it has to go further out.
+/// let (grown, distance) = offset_to_target_area(&squares, 400.0, Backend::FloCurves, 0.5).unwrap();
+/// assert_eq!(grown.subpath_count(), 2);
+/// assert!(distance > 2.141 && distance < 2.5);
+/// ```
+pub fn offset_to_target_area(
+    path: &Path,
+    target_area: f64,
+    backend: Backend,
+    tolerance: f64,
+) -> Result<(Path, f64)> {
+    let signed_area = path.approximate_signed_area(0.01) as f64;
+    if signed_area == 0.0 {
+        return Err(PathError::Unsupported(
+            "the path encloses no area, so no offset distance can be solved for".to_string(),
+        ));
+    }
+
+    // The area enclosed by the offset at `distance`. An offset that shrinks the path away
+    // entirely encloses nothing.
+    let area_at = |distance: f64| -> Result<(Path, f64)> {
+        match backend.offset_path(path, distance) {
+            Ok(result) => {
+                // Loops that wind against their role would cancel out the area of the others.
+                let area = result
+                    .normalize_winding()
+                    .approximate_signed_area(0.01)
+                    .abs() as f64;
+                Ok((result, area))
+            }
+            Err(PathError::EmptyResult | PathError::CleanPath) => {
+                Ok((std::iter::empty().collect(), 0.0))
+            }
+            Err(error) => Err(error),
+        }
+    };
+
+    // A positive distance grows paths with a positive signed area.
+    let area = signed_area.abs();
+    let grow = target_area > area;
+    let direction = signed_area.signum() * if grow { 1.0 } else { -1.0 };
+
+    // Bracket the target between `near` (on the side of the original area) and `far`.
+    let mut near = 0.0;
+    let mut far = area.sqrt() / 8.0 * direction;
+    let mut bracketed = false;
+    for _ in 0..32 {
+        let (result, far_area) = area_at(far)?;
+        if (far_area - target_area).abs() <= tolerance {
+            return Ok((result, far));
+        }
+        if (far_area > target_area) == grow {
+            bracketed = true;
+            break;
+        }
+        near = far;
+        far *= 2.0;
+    }
+    if !bracketed {
+        return Err(PathError::Unsupported(format!(
+            "no offset of the path encloses an area of {target_area}"
+        )));
+    }
+
+    let mut best = area_at(near)?;
+    let mut best_distance = near;
+    for _ in 0..64 {
+        let middle = (near + far) / 2.0;
+        let (result, middle_area) = area_at(middle)?;
+        let error = (middle_area - target_area).abs();
+        if error < (best.1 - target_area).abs() {
+            best = (result, middle_area);
+            best_distance = middle;
+        }
+        if error <= tolerance {
+            break;
+        }
+        if (middle_area > target_area) == grow {
+            far = middle;
+        } else {
+            near = middle;
+        }
+    }
+
+    Ok((best.0, best_distance))
+}
+
+/// Offsets a closed polygon given as `f32` coordinates and returns `f32` rings.
+///
+/// This is a thin entry point for render pipelines whose geometry is already `f32`, such as
+/// GPU vertex data. The input is built directly into the crate's `f32` path representation and
+/// the result is flattened straight back into coordinate lists, so no intermediate
+/// [`Point`](crate::path::point::Point) conversions are needed on either side.
+///
+/// # Accuracy
+///
+/// The `flo_curves` backend still computes the offset itself in `f64`, so the only precision
+/// lost compared to offsetting a [`Path`] is the rounding of the input and output to `f32`.
+/// `f32` carries about seven significant digits: coordinates around `1e4` are resolved to
+/// roughly `1e-3`, which is fine for rendering but may be too coarse for CAD data.
+///
+/// # Arguments
+///
+/// * `polygon` - The vertices of a closed polygon.
+/// * `distance` - The distance by which to offset the polygon.
+/// * `backend` - The offsetting implementation to use.
+/// * `tolerance` - The maximum deviation allowed when flattening the offset curves.
+///
+/// # Returns
+///
+/// A `Result` containing one ring of vertices per resulting subpath. Rings are implicitly
+/// closed and do not repeat their first vertex.
+///
+/// # Example
+///
+/// ```
+/// use path_offset::offset::{Backend, Offset, flo_curves::FloCurvesOffset, offset_f32};
+/// use path_offset::path::{Path, event::PathEvent};
+/// use std::str::FromStr;
+///
+/// let square = [[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 10.0]];
+/// let rings = offset_f32(&square, 1.0, Backend::FloCurves, 0.01).unwrap();
+///
+/// let path = Path::from_str("M0,0 L10,0 L10,10 L0,10 Z").unwrap();
+/// let offset_path = FloCurvesOffset::new(1.0).offset_path(&path).unwrap();
+///
+/// // Every segment endpoint of the f64 result is a vertex of the flattened f32 result.
+/// for event in offset_path.events() {
+///     if let PathEvent::Line { to, .. } | PathEvent::Cubic { to, .. } = event {
+///         let matched = rings[0].iter().any(|[x, y]| {
+///             (*x as f64 - to.0).abs() < 1e-4 && (*y as f64 - to.1).abs() < 1e-4
+///         });
+///         assert!(matched);
+///     }
+/// }
+/// ```
+pub fn offset_f32(
+    polygon: &[[f32; 2]],
+    distance: f32,
+    backend: Backend,
+    tolerance: f32,
+) -> Result<Vec<Vec<[f32; 2]>>> {
+    let mut builder = lyon::path::Path::builder();
+    if let Some(([x, y], rest)) = polygon.split_first() {
+        builder.begin(lyon::math::point(*x, *y));
+        for [x, y] in rest {
+            builder.line_to(lyon::math::point(*x, *y));
+        }
+        builder.close();
+    }
+    let path = Path::from(builder.build());
+
+    let offset_path = backend.offset_path(&path, distance as f64)?;
+
+    Ok(offset_path
+        .polylines(tolerance)
+        .into_iter()
+        .map(|(points, _)| points.into_iter().map(|p| [p.x, p.y]).collect())
+        .collect())
 }
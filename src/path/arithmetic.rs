@@ -0,0 +1,314 @@
+//! Provides boolean operations and cleanup built on `flo_curves`'s path arithmetic.
+//!
+//! `flo_curves` implements robust operations on closed Bézier paths. This module exposes
+//! them on this crate's [`Path`](super::Path) type. The boolean operations first compare
+//! bounding boxes so that disjoint inputs never reach the comparatively expensive solver.
+
+use flo_curves::bezier::path::{
+    SimpleBezierPath, path_add, path_intersect, path_remove_interior_points, path_sub,
+};
+
+use crate::error::{PathError, Result};
+
+use super::point::Point;
+
+impl super::Path {
+    /// Removes interior points and self-intersections from the path, keeping only its outline.
+    ///
+    /// This runs the same `flo_curves` cleanup the offsetter applies to its own output, so it
+    /// can be used on self-intersecting paths that did not come from offsetting, such as
+    /// overlapping hand-drawn contours. Each closed subpath is cleaned on its own and the
+    /// resulting loops are recombined into a single path. Open subpaths have no interior and
+    /// are passed through unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `tolerance` - The accuracy used by `flo_curves` when finding intersections.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the cleaned `Path`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// // A single contour tracing two overlapping rectangles, which covers their 5x5
+    /// // overlap twice.
+    /// let path = Path::from_str("M0,0 L20,0 L20,10 L5,10 L5,-5 L15,-5 L15,5 L0,5 Z").unwrap();
+    /// assert_eq!(path.self_intersections(0.01).len(), 3);
+    /// assert_eq!(path.signed_area(0.01), 275.0);
+    ///
+    /// let outline = path.remove_interior_points(0.01).unwrap();
+    /// assert_eq!(outline.subpath_count(), 1);
+    /// assert!(outline.self_intersections(0.01).is_empty());
+    /// assert!((outline.signed_area(0.01) - 225.0).abs() < 0.01);
+    /// ```
+    pub fn remove_interior_points(&self, tolerance: f64) -> Result<super::Path> {
+        Ok(self
+            .iter()
+            .flat_map(|subpath| {
+                if !subpath.is_closed() {
+                    return vec![subpath];
+                }
+
+                let cleaned: Vec<SimpleBezierPath> =
+                    path_remove_interior_points(&vec![SimpleBezierPath::from(&subpath)], tolerance);
+                cleaned.iter().map(super::Path::from).collect()
+            })
+            .collect())
+    }
+
+    /// Computes the boolean union of this path and `other`.
+    ///
+    /// Both paths are treated as closed fills; open subpaths are closed implicitly.
+    /// When the bounding boxes of the two paths do not overlap, the union is simply both
+    /// paths' subpaths and the `flo_curves` solver is skipped. Holes enclosed by the result
+    /// wind opposite to their shells, as after
+    /// [`normalize_winding`](super::Path::normalize_winding).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PathError::EmptyResult`] if both paths are empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::error::PathError;
+    /// use path_offset::path::{FillRule, Path, point::Point};
+    /// use std::str::FromStr;
+    ///
+    /// let a = Path::from_str("M0,0 L10,0 L10,10 L0,10 Z").unwrap();
+    /// let b = Path::from_str("M20,0 L30,0 L30,10 L20,10 Z").unwrap();
+    ///
+    /// // The inputs are passed through verbatim because the solver never runs.
+    /// let union = a.union(&b).unwrap();
+    /// assert_eq!(union.to_string(), "M0,0L10,0L10,10L0,10ZM20,0L30,0L30,10L20,10Z");
+    ///
+    /// // Overlapping inputs merge into a single outline, covering their overlap once.
+    /// let c = Path::from_str("M5,5 L15,5 L15,15 L5,15 Z").unwrap();
+    /// let union = a.union(&c).unwrap();
+    /// assert_eq!(union.subpath_count(), 1);
+    /// assert!((union.signed_area(0.01) - 175.0).abs() < 0.01);
+    ///
+    /// // Two C shapes facing each other close into a ring around a 4x4 hole.
+    /// let left = Path::from_str("M0,0 L6,0 L6,3 L3,3 L3,7 L6,7 L6,10 L0,10 Z").unwrap();
+    /// let right = Path::from_str("M4,0 L10,0 L10,10 L4,10 L4,7 L7,7 L7,3 L4,3 Z").unwrap();
+    /// let ring = left.union(&right).unwrap();
+    /// assert_eq!(ring.subpath_count(), 2);
+    /// assert!((ring.signed_area(0.01) - 84.0).abs() < 0.01);
+    /// assert!(!ring.contains_point(Point(5.0, 5.0), FillRule::NonZero, 0.01));
+    /// assert!(ring.contains_point(Point(1.0, 5.0), FillRule::NonZero, 0.01));
+    ///
+    /// let empty = Path::from_str("").unwrap();
+    /// assert!(matches!(empty.union(&empty), Err(PathError::EmptyResult)));
+    /// ```
+    pub fn union(&self, other: &super::Path) -> Result<super::Path> {
+        if !self.bbox_intersects(other) {
+            let path: super::Path = self.iter().chain(other.iter()).collect();
+            if path.subpath_count() == 0 {
+                return Err(PathError::EmptyResult);
+            }
+            return Ok(path);
+        }
+
+        let result: Vec<SimpleBezierPath> = path_add(
+            &self.simple_bezier_paths(),
+            &other.simple_bezier_paths(),
+            0.01,
+        );
+        boolean_result(&result)
+    }
+
+    /// Computes the boolean intersection of this path and `other`.
+    ///
+    /// Both paths are treated as closed fills; open subpaths are closed implicitly.
+    /// When the bounding boxes of the two paths do not overlap, the `flo_curves` solver is
+    /// skipped. Holes in the result wind opposite to their shells, as after
+    /// [`normalize_winding`](super::Path::normalize_winding).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PathError::EmptyResult`] if the paths do not overlap.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::error::PathError;
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// let a = Path::from_str("M0,0 L10,0 L10,10 L0,10 Z").unwrap();
+    /// let b = Path::from_str("M5,5 L15,5 L15,15 L5,15 Z").unwrap();
+    /// let intersection = a.intersection(&b).unwrap();
+    /// assert!((intersection.signed_area(0.01) - 25.0).abs() < 0.01);
+    ///
+    /// let c = Path::from_str("M20,0 L30,0 L30,10 L20,10 Z").unwrap();
+    /// assert!(matches!(a.intersection(&c), Err(PathError::EmptyResult)));
+    /// ```
+    pub fn intersection(&self, other: &super::Path) -> Result<super::Path> {
+        if !self.bbox_intersects(other) {
+            return Err(PathError::EmptyResult);
+        }
+
+        let result: Vec<SimpleBezierPath> = path_intersect(
+            &self.simple_bezier_paths(),
+            &other.simple_bezier_paths(),
+            0.01,
+        );
+        boolean_result(&result)
+    }
+
+    /// Computes the boolean difference of this path minus `other`.
+    ///
+    /// Both paths are treated as closed fills; open subpaths are closed implicitly.
+    /// When the bounding boxes of the two paths do not overlap, nothing is removed and
+    /// this path is returned unchanged without running the `flo_curves` solver. Holes cut
+    /// into the result wind opposite to their shells, as after
+    /// [`normalize_winding`](super::Path::normalize_winding), so subtracting holes from
+    /// shells after offsetting them separately gives a path that fills correctly with
+    /// either fill rule.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PathError::EmptyResult`] if `other` covers this path entirely.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::error::PathError;
+    /// use path_offset::path::{FillRule, Path, point::Point};
+    /// use std::str::FromStr;
+    ///
+    /// let big = Path::from_str("M0,0 L10,0 L10,10 L0,10 Z").unwrap();
+    /// let small = Path::from_str("M3,3 L7,3 L7,7 L3,7 Z").unwrap();
+    ///
+    /// let frame = big.difference(&small).unwrap();
+    /// assert_eq!(frame.subpath_count(), 2);
+    /// assert!((frame.signed_area(0.01) - 84.0).abs() < 0.01);
+    /// assert!(!frame.contains_point(Point(5.0, 5.0), FillRule::NonZero, 0.01));
+    /// assert!(frame.contains_point(Point(1.0, 5.0), FillRule::NonZero, 0.01));
+    ///
+    /// assert!(matches!(small.difference(&big), Err(PathError::EmptyResult)));
+    /// ```
+    pub fn difference(&self, other: &super::Path) -> Result<super::Path> {
+        if !self.bbox_intersects(other) {
+            return Ok(self.clone());
+        }
+
+        let result: Vec<SimpleBezierPath> = path_sub(
+            &self.simple_bezier_paths(),
+            &other.simple_bezier_paths(),
+            0.01,
+        );
+        boolean_result(&result)
+    }
+
+    /// Splits the path by an infinite line into the portions on either side of it.
+    ///
+    /// Two boxes are derived from the bounding box of the path, each covering it entirely on
+    /// one side of the line, and the path is intersected with each of them. This sections a
+    /// part into two closed pieces, for example to machine it in two setups.
+    ///
+    /// # Arguments
+    ///
+    /// * `point` - Any point on the line.
+    /// * `direction` - The direction of the line. It does not need to be normalized, but must
+    ///   not be zero; a zero direction puts the whole path on the first side.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the portion to the left of `direction` (counter-clockwise from it
+    /// in a y-up coordinate system) and the portion to its right.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::{Path, point::Point};
+    /// use std::str::FromStr;
+    ///
+    /// let square = Path::from_str("M0,0 L10,0 L10,10 L0,10 Z").unwrap();
+    /// let (above, below) = square.split_by_line(Point(0.0, 5.0), Point(1.0, 0.0)).unwrap();
+    ///
+    /// let near = |a: Point, b: Point| (a.0 - b.0).abs() < 0.01 && (a.1 - b.1).abs() < 0.01;
+    /// let (min, max) = above.report(0.01).bounds.unwrap();
+    /// assert!(near(min, Point(0.0, 5.0)) && near(max, Point(10.0, 10.0)));
+    /// let (min, max) = below.report(0.01).bounds.unwrap();
+    /// assert!(near(min, Point(0.0, 0.0)) && near(max, Point(10.0, 5.0)));
+    /// ```
+    pub fn split_by_line(
+        &self,
+        point: Point,
+        direction: Point,
+    ) -> Result<(super::Path, super::Path)> {
+        let length = direction.0.hypot(direction.1);
+        if length == 0.0 || self.inner.iter().next().is_none() {
+            return Ok((self.clone(), std::iter::empty().collect()));
+        }
+        let (dx, dy) = (direction.0 / length, direction.1 / length);
+
+        // Every point of the path lies within `reach` of `point`.
+        let bbox = lyon::algorithms::aabb::bounding_box(self.inner.iter());
+        let corners = [
+            (bbox.min.x, bbox.min.y),
+            (bbox.max.x, bbox.min.y),
+            (bbox.max.x, bbox.max.y),
+            (bbox.min.x, bbox.max.y),
+        ];
+        let reach = corners
+            .iter()
+            .map(|(x, y)| (*x as f64 - point.0).hypot(*y as f64 - point.1))
+            .fold(0.0, f64::max)
+            + 1.0;
+
+        // A box spanning `reach` along the line on both sides of `point`, and `reach` away
+        // from it along `normal`.
+        let half_plane = |normal: (f64, f64)| -> super::Path {
+            let along = (dx * reach, dy * reach);
+            let across = (normal.0 * reach, normal.1 * reach);
+            let corner = |a: f64, b: f64| {
+                lyon::math::point(
+                    (point.0 + along.0 * a + across.0 * b) as f32,
+                    (point.1 + along.1 * a + across.1 * b) as f32,
+                )
+            };
+
+            let mut builder = lyon::path::Path::builder();
+            builder.begin(corner(-1.0, 0.0));
+            builder.line_to(corner(1.0, 0.0));
+            builder.line_to(corner(1.0, 1.0));
+            builder.line_to(corner(-1.0, 1.0));
+            builder.close();
+            super::Path::from(builder.build())
+        };
+
+        // A side of the line that the path does not reach is left empty.
+        let side = |normal| match self.intersection(&half_plane(normal)) {
+            Err(PathError::EmptyResult) => Ok(std::iter::empty().collect()),
+            result => result,
+        };
+        Ok((side((-dy, dx))?, side((dy, -dx))?))
+    }
+
+    /// Converts every subpath into a `flo_curves` path for use with its arithmetic.
+    fn simple_bezier_paths(&self) -> Vec<SimpleBezierPath> {
+        self.iter()
+            .map(|subpath| SimpleBezierPath::from(&subpath))
+            .collect()
+    }
+}
+
+/// Converts the output of a `flo_curves` boolean operation into a path, with holes winding
+/// opposite to their shells.
+///
+/// `flo_curves` winds every loop it outputs the same way, which only fills correctly with
+/// the even-odd rule.
+fn boolean_result(result: &Vec<SimpleBezierPath>) -> Result<super::Path> {
+    let path = super::Path::from(result);
+    if path.subpath_count() == 0 {
+        return Err(PathError::EmptyResult);
+    }
+    Ok(path.normalize_winding())
+}
@@ -0,0 +1,158 @@
+//! Defines [`Builder`], which builds a [`Path`](super::Path) segment by segment.
+//!
+//! The builder works in canonical [`Point`]s, so paths can be built from scratch without
+//! depending on `lyon` or its `f32` coordinates. Coordinates are stored in the path with
+//! `f32` precision, as with every other way of creating a path.
+
+use super::point::{Point, PointConvert};
+
+/// Builds a [`Path`](super::Path) from subpaths described one segment at a time.
+///
+/// Each subpath starts with [`Builder::begin`], continues with any number of segments, and
+/// finishes with [`Builder::close`] or [`Builder::end`]. The builder follows the rules of
+/// SVG path data where a step is missing: beginning a subpath while another is still in
+/// progress ends the previous one open, and a segment added after a subpath has finished
+/// starts a new one where the current point is, the last point of an open subpath or the
+/// first point of a closed one. [`Builder::build`] ends a subpath left in progress.
+///
+/// # Example
+///
+/// ```
+/// use path_offset::path::{Builder, Path, point::Point};
+/// use std::str::FromStr;
+///
+/// let mut builder = Builder::new();
+/// builder
+///     .begin(Point(0.0, 0.0))
+///     .line_to(Point(10.0, 0.0))
+///     .line_to(Point(10.0, 10.0))
+///     .line_to(Point(0.0, 10.0))
+///     .close();
+/// builder
+///     .begin(Point(20.0, 0.0))
+///     .quadratic_to(Point(25.0, 5.0), Point(30.0, 0.0))
+///     .cubic_to(Point(35.0, -5.0), Point(40.0, 5.0), Point(45.0, 0.0))
+///     .end();
+/// let path = builder.build();
+///
+/// let parsed = Path::from_str("M0,0 L10,0 L10,10 L0,10 Z M20,0 Q25,5 30,0 C35,-5 40,5 45,0")
+///     .unwrap();
+/// assert!(path.events().eq(parsed.events()));
+/// ```
+///
+/// Missing steps are filled in as SVG path data would:
+///
+/// ```
+/// use path_offset::path::{Builder, point::Point};
+///
+/// let mut builder = Builder::new();
+/// builder.begin(Point(0.0, 0.0)).line_to(Point(10.0, 0.0)).line_to(Point(10.0, 10.0)).close();
+/// // After closing, the next segment starts back at the first point.
+/// builder.line_to(Point(-10.0, 0.0));
+/// builder.begin(Point(20.0, 0.0)).line_to(Point(30.0, 0.0));
+///
+/// assert_eq!(builder.build().to_string(), "M0,0L10,0L10,10ZM0,0L-10,0M20,0L30,0");
+/// ```
+pub struct Builder {
+    inner: lyon::path::path::Builder,
+    /// Where a segment starting outside a subpath begins.
+    current: Point,
+    /// The first point of the subpath in progress.
+    first: Point,
+    /// Whether a subpath is in progress.
+    open: bool,
+}
+
+impl Builder {
+    /// Creates an empty builder.
+    pub fn new() -> Self {
+        Builder {
+            inner: lyon::path::Path::builder(),
+            current: Point(0.0, 0.0),
+            first: Point(0.0, 0.0),
+            open: false,
+        }
+    }
+
+    /// Starts a new subpath at `at`, ending the subpath in progress open if there is one.
+    pub fn begin(&mut self, at: Point) -> &mut Self {
+        if self.open {
+            self.end();
+        }
+        self.inner.begin(at.use_as());
+        self.current = at;
+        self.first = at;
+        self.open = true;
+        self
+    }
+
+    /// Adds a line segment to `to`.
+    pub fn line_to(&mut self, to: Point) -> &mut Self {
+        self.ensure_open();
+        self.inner.line_to(to.use_as());
+        self.current = to;
+        self
+    }
+
+    /// Adds a quadratic Bézier segment to `to`, with the control point `ctrl`.
+    pub fn quadratic_to(&mut self, ctrl: Point, to: Point) -> &mut Self {
+        self.ensure_open();
+        self.inner.quadratic_bezier_to(ctrl.use_as(), to.use_as());
+        self.current = to;
+        self
+    }
+
+    /// Adds a cubic Bézier segment to `to`, with the control points `ctrl1` and `ctrl2`.
+    pub fn cubic_to(&mut self, ctrl1: Point, ctrl2: Point, to: Point) -> &mut Self {
+        self.ensure_open();
+        self.inner
+            .cubic_bezier_to(ctrl1.use_as(), ctrl2.use_as(), to.use_as());
+        self.current = to;
+        self
+    }
+
+    /// Finishes the subpath in progress, closing it back to its first point.
+    ///
+    /// Does nothing if no subpath is in progress.
+    pub fn close(&mut self) -> &mut Self {
+        if self.open {
+            self.inner.end(true);
+            self.current = self.first;
+            self.open = false;
+        }
+        self
+    }
+
+    /// Finishes the subpath in progress, leaving it open.
+    ///
+    /// Does nothing if no subpath is in progress.
+    pub fn end(&mut self) -> &mut Self {
+        if self.open {
+            self.inner.end(false);
+            self.open = false;
+        }
+        self
+    }
+
+    /// Builds the path, ending the subpath in progress open if there is one.
+    pub fn build(mut self) -> super::Path {
+        self.end();
+        super::Path {
+            inner: self.inner.build(),
+            scalars: None,
+        }
+    }
+
+    /// Starts a subpath at the current point if none is in progress.
+    fn ensure_open(&mut self) {
+        if !self.open {
+            self.begin(self.current);
+        }
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
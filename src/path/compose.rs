@@ -0,0 +1,173 @@
+//! Provides ways of combining several paths into one.
+
+use lyon::path::Event;
+
+impl super::Path {
+    /// Returns a path with the subpaths of this path followed by those of `other`.
+    ///
+    /// The event streams are concatenated as they are, so every subpath keeps its own
+    /// moveto and closedness, and the subpath count of the result is the sum of both.
+    /// Per-vertex scalars are kept if both paths carry them. See
+    /// [`Path::concat_preserving_first_moveto`] to join touching open subpaths instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// let shell = Path::from_str("M0,0 L10,0 L10,10 L0,10 Z").unwrap();
+    /// let hole = Path::from_str("M3,3 L3,7 L7,7 L7,3 Z").unwrap();
+    /// let guide = Path::from_str("M12,0 L12,10").unwrap();
+    ///
+    /// let shape = shell.append(&hole).append(&guide);
+    /// assert_eq!(
+    ///     shape.subpath_count(),
+    ///     shell.subpath_count() + hole.subpath_count() + guide.subpath_count()
+    /// );
+    /// assert_eq!(shape.signed_area(0.01), 84.0);
+    ///
+    /// let closed: Vec<bool> = shape.iter().map(|subpath| subpath.is_closed()).collect();
+    /// assert_eq!(closed, [true, true, false]);
+    /// ```
+    pub fn append(&self, other: &super::Path) -> super::Path {
+        let mut path = self.clone();
+        path.extend(other);
+        path
+    }
+
+    /// Appends the subpaths of `other` to this path in place.
+    ///
+    /// This is the in-place form of [`Path::append`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// let mut path = Path::from_str("M0,0 L10,0").unwrap();
+    /// path.extend(&Path::from_str("M0,5 L10,5 L10,6 Z").unwrap());
+    ///
+    /// assert_eq!(path.to_string(), "M0,0L10,0M0,5L10,5L10,6Z");
+    /// ```
+    pub fn extend(&mut self, other: &super::Path) {
+        let mut builder = lyon::path::Path::builder();
+        builder.extend_from_paths(&[self.inner.as_slice(), other.inner.as_slice()]);
+        self.inner = builder.build();
+
+        self.scalars = match (self.scalars.take(), &other.scalars) {
+            (Some(mut scalars), Some(other_scalars)) => {
+                scalars.extend_from_slice(other_scalars);
+                Some(scalars)
+            }
+            _ => None,
+        };
+    }
+
+    /// Concatenates `other` onto this path, joining touching open subpaths into one.
+    ///
+    /// Whenever an open subpath ends within `tolerance` of where the next open subpath
+    /// begins, the two are merged: instead of a new `M` command, the next subpath continues
+    /// the current one, with a connecting `L` segment bridging any remaining gap. This
+    /// chains offset pieces into a single continuous toolpath with one initial moveto.
+    /// Closed subpaths, and open subpaths that do not touch, are kept as separate subpaths.
+    /// Per-vertex scalars are kept if both paths carry them.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The path to append after this one.
+    /// * `tolerance` - The maximum gap between two endpoints that are still merged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// let a = Path::from_str("M0,0 L10,0").unwrap();
+    /// let b = Path::from_str("M10,0 L10,10").unwrap();
+    /// let c = Path::from_str("M10.001,10 L0,10").unwrap();
+    ///
+    /// let toolpath = a
+    ///     .concat_preserving_first_moveto(&b, 0.01)
+    ///     .concat_preserving_first_moveto(&c, 0.01);
+    ///
+    /// assert_eq!(toolpath.subpath_count(), 1);
+    /// assert_eq!(toolpath.to_string(), "M0,0L10,0L10,10L10.001,10L0,10");
+    /// ```
+    pub fn concat_preserving_first_moveto(
+        &self,
+        other: &super::Path,
+        tolerance: f32,
+    ) -> super::Path {
+        let mut builder = lyon::path::Path::builder();
+        // The last point of an open subpath that has not been ended yet.
+        let mut pending_end: Option<lyon::math::Point> = None;
+        // Scalars are only kept if both paths carry them.
+        let mut scalars = (self.scalars.is_some() && other.scalars.is_some()).then(Vec::new);
+
+        for subpath in self.iter().chain(other.iter()) {
+            let closed = subpath.is_closed();
+            let mut subpath_scalars = subpath.scalars().unwrap_or_default().iter().copied();
+
+            for event in subpath.inner.iter() {
+                let mut scalar = match event {
+                    Event::End { .. } => None,
+                    _ => subpath_scalars.next(),
+                };
+
+                match event {
+                    Event::Begin { at } => match pending_end.take() {
+                        Some(end) if !closed && (at - end).length() <= tolerance => {
+                            if at != end {
+                                builder.line_to(at);
+                            } else {
+                                // The shared endpoint is only emitted once.
+                                scalar = None;
+                            }
+                        }
+                        Some(_) => {
+                            builder.end(false);
+                            builder.begin(at);
+                        }
+                        None => {
+                            builder.begin(at);
+                        }
+                    },
+                    Event::Line { to, .. } => {
+                        builder.line_to(to);
+                    }
+                    Event::Quadratic { ctrl, to, .. } => {
+                        builder.quadratic_bezier_to(ctrl, to);
+                    }
+                    Event::Cubic {
+                        ctrl1, ctrl2, to, ..
+                    } => {
+                        builder.cubic_bezier_to(ctrl1, ctrl2, to);
+                    }
+                    Event::End { last, close, .. } => {
+                        if close {
+                            builder.end(true);
+                        } else {
+                            pending_end = Some(last);
+                        }
+                    }
+                }
+
+                if let (Some(scalars), Some(scalar)) = (&mut scalars, scalar) {
+                    scalars.push(scalar);
+                }
+            }
+        }
+
+        if pending_end.is_some() {
+            builder.end(false);
+        }
+
+        super::Path {
+            inner: builder.build(),
+            scalars,
+        }
+    }
+}
@@ -0,0 +1,107 @@
+//! Classifies the subpaths of a path as shells or holes, and by orientation.
+//!
+//! A shape with holes is stored as a flat list of subpaths. Their nesting is recovered from
+//! geometric containment with the even-odd rule: a subpath inside an even number of others
+//! bounds material from the outside, one inside an odd number bounds it from the inside.
+
+use super::FillRule;
+
+/// The role of a subpath within the nesting of a path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ContourKind {
+    /// A subpath contained by an even number of other subpaths, such as the outline of a
+    /// shape or an island inside a hole.
+    Shell,
+    /// A subpath contained by an odd number of other subpaths.
+    Hole,
+}
+
+/// The direction in which a closed subpath runs around its interior.
+///
+/// Directions are named with the y axis pointing up, so they are mirrored on screen when the
+/// y axis points down, as in SVG: a `CounterClockwise` subpath has a positive
+/// [`signed_area`](super::Path::signed_area) and looks clockwise in an SVG viewer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    /// A subpath with a negative signed area, which a positive offset distance shrinks.
+    Clockwise,
+    /// A subpath with a positive signed area, which a positive offset distance grows.
+    CounterClockwise,
+}
+
+impl super::Path {
+    /// Classifies each subpath as a shell or a hole, in subpath order.
+    ///
+    /// The classification follows the even-odd rule applied to the containment depth of each
+    /// subpath, the number of other subpaths that contain it. Open subpaths enclose nothing
+    /// and are never contained, so they are always reported as shells.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::{Path, contour::ContourKind};
+    /// use std::str::FromStr;
+    ///
+    /// // A square with a hole, an island inside the hole, and a separate square.
+    /// let path = Path::from_str(
+    ///     "M0,0 L30,0 L30,30 L0,30 Z M5,5 L5,25 L25,25 L25,5 Z M10,10 L20,10 L20,20 L10,20 Z \
+    ///      M40,0 L50,0 L50,10 L40,10 Z",
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(
+    ///     path.classify_contours(),
+    ///     [ContourKind::Shell, ContourKind::Hole, ContourKind::Shell, ContourKind::Shell],
+    /// );
+    /// ```
+    ///
+    /// Containment is tested with a precision relative to the size of the subpaths, so the
+    /// result does not depend on the scale of the coordinates:
+    ///
+    /// ```
+    /// use path_offset::path::{Path, contour::ContourKind, transform::Transform};
+    /// use std::str::FromStr;
+    ///
+    /// // A circle of diameter 1, and a small square just inside its edge.
+    /// let k = 0.5522847 * 0.5;
+    /// let path = Path::from_str(&format!(
+    ///     "M1,0.5 C1,{a} {a},1 0.5,1 C{b},1 0,{a} 0,0.5 C0,{b} {b},0 0.5,0 C{a},0 1,{b} 1,0.5 Z \
+    ///      M0.95,0.66 L0.9,0.66 L0.9,0.61 L0.95,0.61 Z",
+    ///     a = 0.5 + k,
+    ///     b = 0.5 - k,
+    /// ))
+    /// .unwrap();
+    ///
+    /// let expected = [ContourKind::Shell, ContourKind::Hole];
+    /// assert_eq!(path.classify_contours(), expected);
+    /// let scaled = path.transform(&Transform::scale(10000.0, 10000.0));
+    /// assert_eq!(scaled.classify_contours(), expected);
+    /// ```
+    pub fn classify_contours(&self) -> Vec<ContourKind> {
+        self.containment_depths()
+            .into_iter()
+            .map(|depth| {
+                if depth % 2 == 0 {
+                    ContourKind::Shell
+                } else {
+                    ContourKind::Hole
+                }
+            })
+            .collect()
+    }
+
+    /// Returns, for each subpath, the number of other subpaths that contain it.
+    pub(crate) fn containment_depths(&self) -> Vec<usize> {
+        let subpaths: Vec<super::Path> = self.iter().collect();
+
+        subpaths
+            .iter()
+            .map(|subpath| {
+                subpaths
+                    .iter()
+                    .filter(|other| subpath.contained_by(other, FillRule::EvenOdd))
+                    .count()
+            })
+            .collect()
+    }
+}
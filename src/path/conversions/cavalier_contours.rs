@@ -1,6 +1,369 @@
 //! Provides conversions to and from `cavalier_contours` path types.
 //!
-//! This module is intended to house `From` trait implementations that allow
-//! for seamless interoperability between `path-offset`'s path representation
-//! and the path types used by the `cavalier_contours` library.
-// (Implementation pending)
+//! This module allows for interoperability with the `cavalier_contours` library by
+//! converting between this crate's [`Path`](crate::path::Path) and `cavalier_contours`'s
+//! `Polyline<f64>`. A polyline is made of vertices joined by lines and circular arcs, where
+//! each vertex carries a `bulge` describing the segment that starts at it: `0` for a line,
+//! `tan(sweep / 4)` for an arc, positive when the arc turns counter-clockwise.
+
+use std::f64::consts::FRAC_PI_2;
+
+use cavalier_contours::{
+    core::math::Vector2,
+    polyline::{PlineSource, PlineSourceMut, Polyline},
+};
+use lyon::{
+    geom::{CubicBezierSegment, QuadraticBezierSegment, euclid::vec2},
+    path::Event,
+};
+
+use crate::path::point::{Point, PointConvert};
+
+/// The number of cubic curves approximating each quarter turn of an arc by default.
+pub(crate) const DEFAULT_ARC_SEGMENTS_PER_QUADRANT: usize = 1;
+
+/// The maximum distance a curve may deviate from the arcs or lines replacing it.
+const TOLERANCE: f64 = 0.01;
+
+/// The distance within which two consecutive vertices are considered the same point.
+///
+/// `cavalier_contours` requires consecutive vertices to be distinct, but the `f32`
+/// coordinates of a path can leave a closing point a few ulps away from its start.
+const REPEAT_DISTANCE: f64 = 1e-5;
+
+/// The parameters at which a curve is sampled to check that it follows a circular arc.
+const ARC_SAMPLES: [f64; 6] = [0.125, 0.25, 0.375, 0.625, 0.75, 0.875];
+
+/// Converts a reference to a [`Path`](crate::path::Path) into a `cavalier_contours::Polyline<f64>`.
+///
+/// A polyline describes a single contour, so only the first subpath is converted; iterate
+/// over the subpaths to convert each of them. The event stream is processed as follows:
+/// - `Event::Line`: Becomes a vertex with a bulge of `0`.
+/// - `Event::Quadratic`, `Event::Cubic`: Curves that follow a circular arc to within a
+///   small tolerance become arc vertices with the matching bulge, split in two when the arc
+///   sweeps more than a half circle. Any other curve is flattened into line vertices.
+/// - `Event::End`: A closed subpath produces a closed polyline. A final vertex repeating the
+///   start point is dropped, since a closed polyline returns to its start implicitly.
+///
+/// # Example
+///
+/// ```
+/// use cavalier_contours::polyline::{PlineSource, Polyline};
+/// use path_offset::path::Path;
+/// use std::str::FromStr;
+///
+/// let square = Path::from_str("M0,0 L10,0 L10,10 L0,10 Z").unwrap();
+///
+/// let polyline = Polyline::from(&square);
+/// assert!(polyline.is_closed());
+/// assert_eq!(polyline.vertex_count(), 4);
+/// assert!(polyline.iter_vertexes().all(|vertex| vertex.bulge == 0.0));
+///
+/// let round_trip = Path::from(&polyline);
+/// assert_eq!(round_trip.to_string(), square.to_string());
+///
+/// // Quarter circles become arc vertices, with a bulge of tan(90° / 4).
+/// let k = 5.522847;
+/// let circle = Path::from_str(&format!(
+///     "M10,0 C10,{k} {k},10 0,10 C-{k},10 -10,{k} -10,0 C-10,-{k} -{k},-10 0,-10 C{k},-10 10,-{k} 10,0 Z"
+/// ))
+/// .unwrap();
+/// let polyline = Polyline::from(&circle);
+/// assert_eq!(polyline.vertex_count(), 4);
+/// assert!(polyline.iter_vertexes().all(|vertex| (vertex.bulge - 0.41421).abs() < 1e-4));
+/// ```
+impl From<&crate::path::Path> for Polyline<f64> {
+    fn from(path: &crate::path::Path) -> Polyline<f64> {
+        let mut polyline = Polyline::new();
+
+        for event in path.inner.iter() {
+            match event {
+                Event::Begin { at } => {
+                    let at: Point = at.use_as();
+                    polyline.add(at.0, at.1, 0.0);
+                }
+                Event::Line { to, .. } => add_point(&mut polyline, to.use_as()),
+                Event::Quadratic { from, ctrl, to } => {
+                    let curve = QuadraticBezierSegment { from, ctrl, to }.to_cubic();
+                    add_curve(&mut polyline, &curve);
+                }
+                Event::Cubic {
+                    from,
+                    ctrl1,
+                    ctrl2,
+                    to,
+                } => {
+                    let curve = CubicBezierSegment {
+                        from,
+                        ctrl1,
+                        ctrl2,
+                        to,
+                    };
+                    add_curve(&mut polyline, &curve);
+                }
+                Event::End { close, .. } => {
+                    if close {
+                        polyline.set_is_closed(true);
+                        let first = polyline.at(0);
+                        if polyline.vertex_count() > 1
+                            && polyline.last().is_some_and(|last| {
+                                (last.pos() - first.pos()).length() <= REPEAT_DISTANCE
+                            })
+                        {
+                            polyline.remove_last();
+                        }
+                    }
+                    break;
+                }
+            }
+        }
+
+        polyline
+    }
+}
+
+/// Converts a `cavalier_contours::Polyline<f64>` back into a [`Path`](crate::path::Path).
+///
+/// The polyline becomes a single subpath: vertices with a zero bulge start line segments,
+/// and arc segments are approximated by cubic Bézier curves, since `lyon` has no arc
+/// segments of its own. A closed polyline produces a closed subpath, including its closing
+/// segment when that segment is an arc. Each quarter turn of an arc becomes one cubic
+/// curve; see [`Path::from_polyline`](crate::path::Path::from_polyline) to use more.
+impl From<&Polyline<f64>> for crate::path::Path {
+    fn from(polyline: &Polyline<f64>) -> Self {
+        crate::path::Path::from_polyline(polyline, DEFAULT_ARC_SEGMENTS_PER_QUADRANT)
+    }
+}
+
+impl crate::path::Path {
+    /// Converts a `cavalier_contours::Polyline<f64>` into a path, choosing how finely its
+    /// arcs are approximated.
+    ///
+    /// This is the conversion of `From<&Polyline<f64>>`, except that every quarter turn of
+    /// an arc is approximated by `arc_segments_per_quadrant` cubic curves instead of one.
+    /// A single cubic curve per quarter turn strays from the circle by about `0.03%` of its
+    /// radius, and the error falls quickly with more curves, at the cost of a larger path.
+    ///
+    /// # Arguments
+    ///
+    /// * `polyline` - The polyline to convert.
+    /// * `arc_segments_per_quadrant` - The number of cubic curves per quarter turn of an
+    ///   arc. A value of `0` is treated as `1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use cavalier_contours::polyline::{PlineSource, PlineSourceMut, Polyline};
+    /// use path_offset::path::Path;
+    ///
+    /// // A full circle of radius 10, as two half-circle arcs.
+    /// let mut circle = Polyline::new_closed();
+    /// circle.add(10.0, 0.0, 1.0);
+    /// circle.add(-10.0, 0.0, 1.0);
+    ///
+    /// let coarse = Path::from_polyline(&circle, 1);
+    /// let fine = Path::from_polyline(&circle, 4);
+    /// assert_eq!(coarse.segment_count(), 4);
+    /// assert_eq!(fine.segment_count(), 16);
+    /// ```
+    pub fn from_polyline(polyline: &Polyline<f64>, arc_segments_per_quadrant: usize) -> Self {
+        let mut builder = lyon::path::Path::builder();
+
+        if !polyline.is_empty() {
+            builder.begin(Point::from(polyline.at(0).pos()).use_as());
+
+            for segment in 0..polyline.segment_count() {
+                let start = polyline.at(segment);
+                let end = polyline.at(polyline.next_wrapping_index(segment));
+                let is_closing = segment + 1 == polyline.vertex_count();
+
+                if start.bulge_is_zero() {
+                    if !is_closing {
+                        builder.line_to(Point::from(end.pos()).use_as());
+                    }
+                    continue;
+                }
+
+                let (_, center) =
+                    cavalier_contours::polyline::seg_arc_radius_and_center(start, end);
+                let curves = arc_curves(
+                    lyon::geom::point(center.x, center.y),
+                    lyon::geom::point(start.x, start.y),
+                    lyon::geom::point(end.x, end.y),
+                    4.0 * start.bulge.atan(),
+                    arc_segments_per_quadrant,
+                );
+                for curve in curves {
+                    builder.cubic_bezier_to(
+                        Point(curve.ctrl1.x, curve.ctrl1.y).use_as(),
+                        Point(curve.ctrl2.x, curve.ctrl2.y).use_as(),
+                        Point(curve.to.x, curve.to.y).use_as(),
+                    );
+                }
+            }
+
+            builder.end(polyline.is_closed());
+        }
+
+        Self {
+            inner: builder.build(),
+            scalars: None,
+        }
+    }
+}
+
+/// Appends a curve to a polyline as arc vertices if it follows a circular arc, or as
+/// flattened line vertices otherwise.
+///
+/// The polyline must already end at the start of the curve; the bulge of that last vertex
+/// describes the segment that follows it.
+fn add_curve(polyline: &mut Polyline<f64>, curve: &CubicBezierSegment<f32>) {
+    let sample = |t: f64| -> Vector2<f64> { Point::from(curve.sample(t as f32)).use_as() };
+    let from = sample(0.0);
+    let middle = sample(0.5);
+    let to = sample(1.0);
+
+    // A curve lying along its chord is a line.
+    let chord = to - from;
+    if chord.length() > TOLERANCE
+        && ARC_SAMPLES
+            .iter()
+            .all(|&t| (chord.perp_dot(sample(t) - from) / chord.length()).abs() <= TOLERANCE)
+    {
+        polyline.add(to.x, to.y, 0.0);
+        return;
+    }
+
+    if let Some(center) = circumcenter(from, middle, to) {
+        let radius = (from - center).length();
+        let follows_arc = ARC_SAMPLES
+            .iter()
+            .all(|&t| ((sample(t) - center).length() - radius).abs() <= TOLERANCE);
+
+        if follows_arc {
+            // A vertex describes at most a half circle, so a longer arc keeps the midpoint
+            // of the curve as an extra vertex.
+            let first = arc_bulge(center, from, middle, sample(0.25));
+            let second = arc_bulge(center, middle, to, sample(0.75));
+            let sweep = 4.0 * (first.atan() + second.atan());
+
+            if sweep.abs() <= std::f64::consts::PI {
+                set_last_bulge(polyline, (sweep / 4.0).tan());
+            } else {
+                set_last_bulge(polyline, first);
+                polyline.add(middle.x, middle.y, second);
+            }
+            polyline.add(to.x, to.y, 0.0);
+            return;
+        }
+    }
+
+    curve.for_each_flattened(TOLERANCE as f32, &mut |segment| {
+        add_point(polyline, segment.to.use_as());
+    });
+}
+
+/// Appends a line vertex to a polyline, unless it repeats the position of the last vertex.
+fn add_point(polyline: &mut Polyline<f64>, point: Point) {
+    let repeats = polyline
+        .last()
+        .is_some_and(|last| (last.x - point.0).hypot(last.y - point.1) <= REPEAT_DISTANCE);
+    if !repeats {
+        polyline.add(point.0, point.1, 0.0);
+    }
+}
+
+/// Sets the bulge of the last vertex of a polyline.
+fn set_last_bulge(polyline: &mut Polyline<f64>, bulge: f64) {
+    if let Some(mut last) = polyline.last() {
+        last.bulge = bulge;
+        polyline.set_last(last);
+    }
+}
+
+/// Returns the bulge of the arc around `center` from `start` to `end` passing through `via`.
+fn arc_bulge(
+    center: Vector2<f64>,
+    start: Vector2<f64>,
+    end: Vector2<f64>,
+    via: Vector2<f64>,
+) -> f64 {
+    let sweep_to = |point: Vector2<f64>| {
+        let a = start - center;
+        let b = point - center;
+        a.perp_dot(b).atan2(a.dot(b))
+    };
+
+    // The signed angle from start to end is ambiguous by a full turn; pick the direction
+    // that passes through `via`.
+    let mut sweep = sweep_to(end);
+    let via_sweep = sweep_to(via);
+    if via_sweep.signum() != sweep.signum() || via_sweep.abs() > sweep.abs() {
+        sweep -= std::f64::consts::TAU.copysign(sweep);
+    }
+
+    (sweep / 4.0).tan()
+}
+
+/// Returns the center of the circle through three points, or `None` if they are collinear.
+fn circumcenter(a: Vector2<f64>, b: Vector2<f64>, c: Vector2<f64>) -> Option<Vector2<f64>> {
+    let ab = b - a;
+    let ac = c - a;
+    let denominator = 2.0 * ab.perp_dot(ac);
+    if denominator.abs() <= f64::EPSILON {
+        return None;
+    }
+
+    let ab_squared = ab.dot(ab);
+    let ac_squared = ac.dot(ac);
+    let offset = Vector2::new(
+        ac.y * ab_squared - ab.y * ac_squared,
+        ab.x * ac_squared - ac.x * ab_squared,
+    );
+    Some(a + offset.scale(1.0 / denominator))
+}
+
+/// Approximates the circular arc around `center` that sweeps `sweep` radians from `start` to
+/// `end` with `arc_segments_per_quadrant` cubic curves per quarter turn.
+///
+/// The curves begin exactly on `start` and finish exactly on `end` rather than on the rounded
+/// ends of the arc, so that the arc meets the segments around it without a gap. This also
+/// approximates the round joins of
+/// [`FloCurvesOffset`](crate::offset::flo_curves::FloCurvesOffset).
+pub(crate) fn arc_curves(
+    center: lyon::geom::Point<f64>,
+    start: lyon::geom::Point<f64>,
+    end: lyon::geom::Point<f64>,
+    sweep: f64,
+    arc_segments_per_quadrant: usize,
+) -> Vec<CubicBezierSegment<f64>> {
+    let radius = (start - center).length();
+    let start_angle = (start.y - center.y).atan2(start.x - center.x);
+    let per_quadrant = arc_segments_per_quadrant.max(1) as f64;
+    // Rounding can leave a quarter turn a hair longer, which should not take another curve.
+    let pieces = (sweep.abs() / FRAC_PI_2 * per_quadrant - 1e-9)
+        .ceil()
+        .max(1.0) as usize;
+    let step = sweep / pieces as f64;
+    // The length of the control handles of a cubic approximating an arc of `step`.
+    let handle = radius * 4.0 / 3.0 * (step / 4.0).tan();
+
+    let point = |angle: f64| center + vec2(angle.cos(), angle.sin()) * radius;
+    let tangent = |angle: f64| vec2(-angle.sin(), angle.cos()) * handle;
+    (0..pieces)
+        .map(|piece| {
+            let (a, b) = (
+                start_angle + step * piece as f64,
+                start_angle + step * (piece + 1) as f64,
+            );
+            let from = if piece == 0 { start } else { point(a) };
+            let to = if piece + 1 == pieces { end } else { point(b) };
+            CubicBezierSegment {
+                from,
+                ctrl1: from + tangent(a),
+                ctrl2: to - tangent(b),
+                to,
+            }
+        })
+        .collect()
+}
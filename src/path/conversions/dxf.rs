@@ -0,0 +1,216 @@
+//! Provides conversions to and from DXF entities.
+//!
+//! This module allows for interoperability with CAD software through the `dxf` crate. DXF
+//! polylines describe their segments with the same bulges as `cavalier_contours` polylines,
+//! so entities go through the conversions of
+//! [`conversions::cavalier_contours`](super::cavalier_contours) in both directions: arcs
+//! become cubic Bézier curves in a [`Path`], and back. It is only available with the `dxf`
+//! feature.
+
+use cavalier_contours::polyline::{PlineCreation, PlineSource, PlineSourceMut, Polyline};
+use dxf::{
+    Drawing, LwPolylineVertex,
+    entities::{Entity, EntityType, LwPolyline},
+};
+
+use crate::path::Path;
+
+impl Path {
+    /// Builds a path from DXF entities, one subpath per supported entity.
+    ///
+    /// - `LWPOLYLINE`: Becomes a subpath with the same vertices, closed if the polyline is,
+    ///   with bulged segments as circular arcs.
+    /// - `LINE`: Becomes an open subpath with a single line segment.
+    /// - `ARC`: Becomes an open subpath running counter-clockwise from the start angle to
+    ///   the end angle, as DXF arcs do.
+    /// - `CIRCLE`: Becomes a closed subpath running counter-clockwise.
+    ///
+    /// Other entities are skipped, as are polylines with fewer than two vertices. Entities
+    /// are read in the XY plane: elevations and z coordinates are dropped, and an entity
+    /// whose extrusion direction points down the z axis, as left by mirroring in many CAD
+    /// programs, is mirrored back.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dxf::{
+    ///     LwPolylineVertex,
+    ///     entities::{Arc, Entity, EntityType, Line, LwPolyline},
+    /// };
+    /// use path_offset::path::{Path, point::Point};
+    ///
+    /// // A 10x10 square with its right side bulging out into a half circle.
+    /// let mut slot = LwPolyline::default();
+    /// slot.set_is_closed(true);
+    /// for (x, y, bulge) in [(0.0, 0.0, 0.0), (10.0, 0.0, 1.0), (10.0, 10.0, 0.0), (0.0, 10.0, 0.0)] {
+    ///     slot.vertices.push(LwPolylineVertex { x, y, bulge, ..Default::default() });
+    /// }
+    /// let line = Line {
+    ///     p1: dxf::Point::new(20.0, 0.0, 0.0),
+    ///     p2: dxf::Point::new(30.0, 0.0, 0.0),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let entities = [
+    ///     Entity::new(EntityType::LwPolyline(slot)),
+    ///     Entity::new(EntityType::Line(line)),
+    /// ];
+    /// let path = Path::from_dxf_entities(&entities);
+    ///
+    /// assert_eq!(path.subpath_count(), 2);
+    /// let slot = path.subpath(0).unwrap();
+    /// assert!(slot.is_closed());
+    /// // The half circle is approximated by cubic curves.
+    /// let area = 100.0 + std::f64::consts::PI * 25.0 / 2.0;
+    /// assert!((slot.signed_area(0.001) - area).abs() < 0.1);
+    /// assert_eq!(path.subpath(1).unwrap().to_string(), "M20,0L30,0");
+    ///
+    /// // Three quarters of a circle, from the positive x axis round to the negative y axis.
+    /// let arc = Arc {
+    ///     center: dxf::Point::new(0.0, 0.0, 0.0),
+    ///     radius: 5.0,
+    ///     start_angle: 0.0,
+    ///     end_angle: 270.0,
+    ///     ..Default::default()
+    /// };
+    /// let arc = Path::from_dxf_entities(&[Entity::new(EntityType::Arc(arc))]);
+    /// let points: Vec<Point> = arc.points().collect();
+    /// assert_eq!(points.first(), Some(&Point(5.0, 0.0)));
+    /// assert!(points.last().unwrap().distance_to(Point(0.0, -5.0)) < 1e-5);
+    /// let (min, max) = arc.report(0.001).bounds.unwrap();
+    /// assert!(min.distance_to(Point(-5.0, -5.0)) < 0.01 && max.distance_to(Point(5.0, 5.0)) < 0.01);
+    /// ```
+    pub fn from_dxf_entities<'a>(entities: impl IntoIterator<Item = &'a Entity>) -> Path {
+        entities
+            .into_iter()
+            .filter_map(|entity| polyline_from_entity(&entity.specific))
+            .map(|polyline| Path::from(&polyline))
+            .collect()
+    }
+
+    /// Converts the path into a DXF drawing with one `LWPOLYLINE` entity per subpath.
+    ///
+    /// Each subpath is converted as a `cavalier_contours` polyline is: curves that follow
+    /// circular arcs become bulged segments, and other curves are flattened into lines. A
+    /// closed subpath gives a closed polyline. The drawing can then be saved with
+    /// `Drawing::save_file`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dxf::entities::EntityType;
+    /// use path_offset::offset::{Offset, cavalier_contours::CavalierContours};
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// // Offsetting a square outward rounds its corners into arcs.
+    /// let square = Path::from_str("M0,0 L10,0 L10,10 L0,10 Z").unwrap();
+    /// let toolpath = CavalierContours::new(1.0).offset_path(&square).unwrap();
+    ///
+    /// let drawing = toolpath.to_dxf();
+    /// let entities: Vec<_> = drawing.entities().collect();
+    /// assert_eq!(entities.len(), 1);
+    /// let EntityType::LwPolyline(polyline) = &entities[0].specific else { panic!() };
+    /// assert!(polyline.is_closed());
+    /// assert_eq!(polyline.vertices.len(), 8);
+    ///
+    /// // Every corner is close to a quarter circle, with a bulge of tan(90° / 4).
+    /// let mut arcs = polyline.vertices.iter().filter(|vertex| vertex.bulge != 0.0);
+    /// assert_eq!(arcs.clone().count(), 4);
+    /// assert!(arcs.all(|vertex| (vertex.bulge - 0.41421).abs() < 0.01));
+    ///
+    /// // Reading the drawing back gives the same outline.
+    /// let round_trip = Path::from_dxf_entities(drawing.entities());
+    /// assert!((round_trip.signed_area(0.001) - toolpath.signed_area(0.001)).abs() < 0.01);
+    /// ```
+    pub fn to_dxf(&self) -> Drawing {
+        let mut drawing = Drawing::new();
+
+        for subpath in self.iter() {
+            let polyline = Polyline::from(&subpath);
+            let mut entity = LwPolyline::default();
+            entity.set_is_closed(polyline.is_closed());
+            entity.vertices = polyline
+                .iter_vertexes()
+                .map(|vertex| LwPolylineVertex {
+                    x: vertex.x,
+                    y: vertex.y,
+                    bulge: vertex.bulge,
+                    ..Default::default()
+                })
+                .collect();
+            drawing.add_entity(Entity::new(EntityType::LwPolyline(entity)));
+        }
+
+        drawing
+    }
+}
+
+/// Converts a supported DXF entity into a `cavalier_contours` polyline.
+fn polyline_from_entity(entity: &EntityType) -> Option<Polyline<f64>> {
+    match entity {
+        EntityType::LwPolyline(source) => {
+            if source.vertices.len() < 2 {
+                return None;
+            }
+            let mut polyline = Polyline::with_capacity(source.vertices.len(), source.is_closed());
+            let mirrored = source.extrusion_direction.z < 0.0;
+            for vertex in &source.vertices {
+                add_vertex(&mut polyline, mirrored, vertex.x, vertex.y, vertex.bulge);
+            }
+            Some(polyline)
+        }
+        EntityType::Line(line) => {
+            let mut polyline = Polyline::with_capacity(2, false);
+            let mirrored = line.extrusion_direction.z < 0.0;
+            add_vertex(&mut polyline, mirrored, line.p1.x, line.p1.y, 0.0);
+            add_vertex(&mut polyline, mirrored, line.p2.x, line.p2.y, 0.0);
+            Some(polyline)
+        }
+        EntityType::Arc(arc) => {
+            // DXF arcs run counter-clockwise, with angles in degrees.
+            let mut sweep = (arc.end_angle - arc.start_angle).rem_euclid(360.0);
+            if sweep == 0.0 {
+                sweep = 360.0;
+            }
+            let at = |degrees: f64| {
+                let (sin, cos) = degrees.to_radians().sin_cos();
+                (
+                    arc.center.x + arc.radius * cos,
+                    arc.center.y + arc.radius * sin,
+                )
+            };
+
+            // A vertex describes at most a half circle, so a longer arc is split in two.
+            let pieces = if sweep > 180.0 { 2 } else { 1 };
+            let bulge = (sweep / pieces as f64).to_radians() / 4.0;
+            let mut polyline = Polyline::with_capacity(pieces + 1, false);
+            let mirrored = arc.normal.z < 0.0;
+            for piece in 0..=pieces {
+                let (x, y) = at(arc.start_angle + sweep * piece as f64 / pieces as f64);
+                let bulge = if piece < pieces { bulge.tan() } else { 0.0 };
+                add_vertex(&mut polyline, mirrored, x, y, bulge);
+            }
+            Some(polyline)
+        }
+        EntityType::Circle(circle) => {
+            let (x, y, radius) = (circle.center.x, circle.center.y, circle.radius);
+            let mut polyline = Polyline::with_capacity(2, true);
+            let mirrored = circle.normal.z < 0.0;
+            add_vertex(&mut polyline, mirrored, x + radius, y, 1.0);
+            add_vertex(&mut polyline, mirrored, x - radius, y, 1.0);
+            Some(polyline)
+        }
+        _ => None,
+    }
+}
+
+/// Appends a vertex to a polyline, mirrored across the y axis if the entity it comes from
+/// has an extrusion direction pointing down the z axis.
+fn add_vertex(polyline: &mut Polyline<f64>, mirrored: bool, x: f64, y: f64, bulge: f64) {
+    if mirrored {
+        polyline.add(-x, y, -bulge);
+    } else {
+        polyline.add(x, y, bulge);
+    }
+}
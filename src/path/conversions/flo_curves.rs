@@ -16,15 +16,25 @@ use lyon::path::Event;
 
 use crate::path::point::PointConvert;
 
+/// The distance below which the end of a subpath is considered to be back at its start.
+///
+/// It absorbs the rounding of `lyon`'s `f32` coordinates: a closing segment shorter than
+/// this carries no geometry, but `flo_curves` cannot offset it reliably.
+const CLOSING_TOLERANCE: f64 = 1e-3;
+
 /// Converts a reference to a [`Path`](crate::path::Path) into a `flo_curves::SimpleBezierPath`.
 ///
+/// A `SimpleBezierPath` describes a single contour, so only the first subpath is converted;
+/// iterate over the subpaths to convert each of them.
+///
 /// This conversion processes the `lyon::path::Event` stream of the input path:
 /// - `Event::Line`, `Event::Cubic`: Translated directly to `flo_curves` equivalents.
 /// - `Event::Quadratic`: Mathematically converted into a cubic Bézier curve, as
 ///   `flo_curves` primarily works with cubic curves.
-/// - `Event::End`: If the path is not marked as closed by `lyon`, a closing line segment
-///   is added to ensure the `flo_curves` path is properly closed, which is often a
-///   requirement for path algorithms.
+/// - `Event::End`: A closing line segment back to the start point is added unless the
+///   path already ends there, whether or not `lyon` marks it as closed. Otherwise the
+///   closing edge of a closed path would be missing from the curves, and would never be
+///   offset.
 impl From<&crate::path::Path> for SimpleBezierPath {
     fn from(path: &crate::path::Path) -> SimpleBezierPath {
         let mut builder = BezierPathBuilder::<SimpleBezierPath>::start(Coord2::from((0.0, 0.0)));
@@ -60,15 +70,15 @@ impl From<&crate::path::Path> for SimpleBezierPath {
                     builder = builder.curve_to((ctrl1.use_as(), ctrl2.use_as()), to_point);
                     current_pos = to_point;
                 }
-                Event::End { first, close, .. } => {
-                    // Manually add a closing line segment only if lyon reports the path as open.
-                    if !close {
-                        // Also check to avoid adding a minuscule line due to floating point errors.
-                        if current_pos.distance_to(&first.use_as()) > 1e-6 {
-                            builder = builder.line_to(first.use_as());
-                        }
+                Event::End { first, .. } => {
+                    // lyon's `close` does not add a segment back to the start, so the closing
+                    // edge is added explicitly whether or not the subpath is marked as closed.
+                    // Skip it if the last point is already at the start, avoiding a minuscule
+                    // line due to floating point errors.
+                    if current_pos.distance_to(&first.use_as()) > CLOSING_TOLERANCE {
+                        builder = builder.line_to(first.use_as());
                     }
-                    // If `close` is true, do nothing, as the path is already perfectly closed.
+                    break;
                 }
             }
         }
@@ -108,6 +118,7 @@ impl From<&Vec<Curve<Coord2>>> for crate::path::Path {
 
         Self {
             inner: builder.build(),
+            scalars: None,
         }
     }
 }
@@ -116,41 +127,90 @@ impl From<&Vec<Curve<Coord2>>> for crate::path::Path {
 ///
 /// This reconstructs a `lyon` path from the `flo_curves` representation. It handles
 /// both lines and cubic curves. The resulting path is explicitly closed by adding a
-/// line segment back to the start point and calling `close()`.
+/// line segment back to the start point, unless the last segment already ends there,
+/// and calling `close()`.
+///
+/// # Example
+///
+/// ```
+/// use flo_curves::{Coord2, bezier::path::SimpleBezierPath};
+/// use path_offset::path::Path;
+///
+/// // A triangle whose last line already returns to the start point.
+/// let triangle: SimpleBezierPath = (
+///     Coord2(0.0, 0.0),
+///     vec![
+///         (Coord2(0.0, 0.0), Coord2(10.0, 0.0), Coord2(10.0, 0.0)),
+///         (Coord2(10.0, 0.0), Coord2(10.0, 10.0), Coord2(10.0, 10.0)),
+///         (Coord2(10.0, 10.0), Coord2(0.0, 0.0), Coord2(0.0, 0.0)),
+///     ],
+/// );
+///
+/// // No zero-length line is inserted before the close.
+/// assert_eq!(Path::from(&triangle).to_string(), "M0,0L10,0L10,10L0,0Z");
+/// ```
 impl From<&SimpleBezierPath> for crate::path::Path {
     fn from(value: &SimpleBezierPath) -> Self {
-        let (start_point, segments) = value;
         let mut builder = lyon::path::Path::builder();
+        append_simple_bezier_path(&mut builder, value);
 
-        // Begin path at the start point
-        builder.begin(start_point.use_as());
+        Self {
+            inner: builder.build(),
+            scalars: None,
+        }
+    }
+}
+
+/// Converts a list of `flo_curves::SimpleBezierPath`s into a multi-subpath [`Path`](crate::path::Path).
+///
+/// Each `SimpleBezierPath` becomes its own closed subpath, in the same order as the input.
+/// This is the natural shape of the output of `flo_curves`'s path arithmetic, which can
+/// return several disjoint loops.
+impl From<&Vec<SimpleBezierPath>> for crate::path::Path {
+    fn from(value: &Vec<SimpleBezierPath>) -> Self {
+        let mut builder = lyon::path::Path::builder();
+        for path in value {
+            append_simple_bezier_path(&mut builder, path);
+        }
 
-        // Track last point for later closure
-        let mut last_point = start_point;
+        Self {
+            inner: builder.build(),
+            scalars: None,
+        }
+    }
+}
 
-        for (ctrl1, ctrl2, to) in segments {
-            if ctrl1.is_nan() || ctrl2.is_nan() || to.is_nan() {
-                continue;
-            }
+/// Appends a `SimpleBezierPath` to a `lyon` builder as a single closed subpath.
+fn append_simple_bezier_path(builder: &mut lyon::path::path::Builder, value: &SimpleBezierPath) {
+    let (start_point, segments) = value;
 
-            // A line is represented in SimpleBezierPath where control points align with endpoints.
-            let is_line = ctrl1 == last_point && ctrl2 == to;
+    // Begin path at the start point
+    builder.begin(start_point.use_as());
 
-            if is_line {
-                builder.line_to(to.use_as());
-            } else {
-                builder.cubic_bezier_to(ctrl1.use_as(), ctrl2.use_as(), to.use_as());
-            }
+    // Track last point for later closure
+    let mut last_point = start_point;
 
-            last_point = to;
+    for (ctrl1, ctrl2, to) in segments {
+        if ctrl1.is_nan() || ctrl2.is_nan() || to.is_nan() {
+            continue;
         }
 
-        // Close the path by returning to the start point.
-        builder.line_to(start_point.use_as());
-        builder.close();
+        // A line is represented in SimpleBezierPath where control points align with endpoints.
+        let is_line = ctrl1 == last_point && ctrl2 == to;
 
-        Self {
-            inner: builder.build(),
+        if is_line {
+            builder.line_to(to.use_as());
+        } else {
+            builder.cubic_bezier_to(ctrl1.use_as(), ctrl2.use_as(), to.use_as());
         }
+
+        last_point = to;
+    }
+
+    // Close the path by returning to the start point, unless the last segment already
+    // ends there.
+    if last_point.distance_to(start_point) > CLOSING_TOLERANCE {
+        builder.line_to(start_point.use_as());
     }
+    builder.close();
 }
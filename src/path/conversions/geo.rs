@@ -0,0 +1,207 @@
+//! Provides conversions between a [`Path`](crate::path::Path) and `geo-types` geometries.
+//!
+//! The `geo` crate and its `geo-types` geometries offer boolean operations, area and
+//! distance calculations, and many other algorithms on polygons. They know nothing of
+//! curves, so curves are flattened into line segments on the way there, within a
+//! `tolerance` given by the caller. This module is only available with the `geo` feature.
+//!
+//! Closed subpaths become the rings of a `MultiPolygon`, and open subpaths the line strings
+//! of a `MultiLineString`.
+
+use geo_types::{Coord, LineString, MultiLineString, MultiPolygon, Polygon};
+
+use crate::path::{FillRule, Path};
+
+impl Path {
+    /// Flattens the closed subpaths of the path into a `geo_types::MultiPolygon`.
+    ///
+    /// Subpaths are nested by containment, as in [`Path::classify_contours`]: every shell
+    /// becomes the exterior of a polygon, and every hole an interior ring of the smallest
+    /// shell around it. Rings are oriented as `geo` expects, exteriors counter-clockwise
+    /// and interiors clockwise with the y axis pointing up, whatever the winding of the
+    /// subpaths. Open subpaths do not bound an area and are skipped; see
+    /// [`Path::to_multi_line_string`].
+    ///
+    /// # Arguments
+    ///
+    /// * `tolerance` - The maximum distance between a curve and the line segments replacing
+    ///   it. Smaller values follow curves more closely, with more vertices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// // A square with a hole, and a separate triangle, along with an open line.
+    /// let path = Path::from_str(
+    ///     "M0,0 L10,0 L10,10 L0,10 Z M3,3 L7,3 L7,7 L3,7 Z M20,0 L30,0 L25,10 Z M0,20 L10,20",
+    /// )
+    /// .unwrap();
+    /// let multi_polygon = path.to_multi_polygon(0.01);
+    ///
+    /// assert_eq!(multi_polygon.0.len(), 2);
+    /// assert_eq!(multi_polygon.0[0].interiors().len(), 1);
+    /// assert_eq!(multi_polygon.0[1].interiors().len(), 0);
+    ///
+    /// // The hole winds like its shell in the path, so it was reversed.
+    /// let hole = &multi_polygon.0[0].interiors()[0];
+    /// assert_eq!(hole.0[1], geo_types::coord! { x: 3.0, y: 7.0 });
+    ///
+    /// // Converting back keeps the closed subpaths.
+    /// let round_trip = Path::from(&multi_polygon);
+    /// assert_eq!(round_trip.subpath_count(), 3);
+    /// assert_eq!(round_trip.signed_area(0.01), 100.0 - 16.0 + 50.0);
+    /// ```
+    pub fn to_multi_polygon(&self, tolerance: f64) -> MultiPolygon<f64> {
+        let closed: Vec<Path> = self.iter().filter(Path::is_closed).collect();
+        let depths: Vec<usize> = closed
+            .iter()
+            .map(|subpath| {
+                closed
+                    .iter()
+                    .filter(|other| subpath.contained_by(other, FillRule::EvenOdd))
+                    .count()
+            })
+            .collect();
+
+        let mut shells = Vec::new();
+        let mut polygons: Vec<Polygon<f64>> = Vec::new();
+        for (index, subpath) in closed.iter().enumerate() {
+            if depths[index].is_multiple_of(2) {
+                shells.push(index);
+                polygons.push(Polygon::new(ring(subpath, tolerance, true), Vec::new()));
+            }
+        }
+
+        for (index, subpath) in closed.iter().enumerate() {
+            if !depths[index].is_multiple_of(2) {
+                // The smallest shell around a hole is the one exactly one level up.
+                let parent = shells.iter().position(|&shell| {
+                    depths[shell] + 1 == depths[index]
+                        && subpath.contained_by(&closed[shell], FillRule::EvenOdd)
+                });
+                if let Some(parent) = parent {
+                    polygons[parent].interiors_push(ring(subpath, tolerance, false));
+                }
+            }
+        }
+
+        MultiPolygon(polygons)
+    }
+
+    /// Flattens the open subpaths of the path into a `geo_types::MultiLineString`.
+    ///
+    /// Each open subpath becomes one line string, in path order. Closed subpaths are skipped;
+    /// see [`Path::to_multi_polygon`].
+    ///
+    /// # Arguments
+    ///
+    /// * `tolerance` - The maximum distance between a curve and the line segments replacing
+    ///   it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// let path = Path::from_str("M0,0 L10,0 L10,10 Z M0,20 L10,20 Q20,20 20,30").unwrap();
+    /// let lines = path.to_multi_line_string(0.01);
+    ///
+    /// assert_eq!(lines.0.len(), 1);
+    /// assert_eq!(lines.0[0].0[0], geo_types::coord! { x: 0.0, y: 20.0 });
+    /// assert!(lines.0[0].0.len() > 3);
+    /// assert_eq!(Path::from(&lines).subpath_count(), 1);
+    /// ```
+    pub fn to_multi_line_string(&self, tolerance: f64) -> MultiLineString<f64> {
+        self.iter()
+            .filter(|subpath| !subpath.is_closed())
+            .flat_map(|subpath| subpath.polylines(tolerance as f32))
+            .map(|(points, _)| LineString(points.into_iter().map(coord).collect()))
+            .collect()
+    }
+}
+
+/// Converts a `geo_types::MultiPolygon` into a [`Path`](crate::path::Path).
+///
+/// Each polygon contributes its exterior, then its interiors, as closed subpaths of line
+/// segments, keeping the orientation of every ring. Rings with fewer than two distinct
+/// points are skipped.
+impl From<&MultiPolygon<f64>> for Path {
+    fn from(multi_polygon: &MultiPolygon<f64>) -> Self {
+        let mut builder = lyon::path::Path::builder();
+        let rings = multi_polygon
+            .iter()
+            .flat_map(|polygon| std::iter::once(polygon.exterior()).chain(polygon.interiors()));
+
+        for ring in rings {
+            // `geo` rings repeat their first point at the end.
+            let mut coords = &ring.0[..];
+            if coords.len() > 1 && coords.first() == coords.last() {
+                coords = &coords[..coords.len() - 1];
+            }
+            add_line_string(&mut builder, coords, true);
+        }
+
+        Path::from(builder.build())
+    }
+}
+
+/// Converts a `geo_types::MultiLineString` into a [`Path`](crate::path::Path).
+///
+/// Each line string becomes an open subpath of line segments. Line strings with fewer than
+/// two points are skipped.
+impl From<&MultiLineString<f64>> for Path {
+    fn from(multi_line_string: &MultiLineString<f64>) -> Self {
+        let mut builder = lyon::path::Path::builder();
+        for line_string in multi_line_string {
+            add_line_string(&mut builder, &line_string.0, false);
+        }
+
+        Path::from(builder.build())
+    }
+}
+
+/// Flattens a closed subpath into a closed `geo` ring with the requested orientation.
+fn ring(subpath: &Path, tolerance: f64, counter_clockwise: bool) -> LineString<f64> {
+    let mut coords: Vec<Coord<f64>> = subpath
+        .polylines(tolerance as f32)
+        .into_iter()
+        .flat_map(|(points, _)| points)
+        .map(coord)
+        .collect();
+
+    // The shoelace formula gives a positive area for counter-clockwise rings.
+    let edges = coords.iter().zip(coords.iter().cycle().skip(1));
+    let area: f64 = edges.map(|(a, b)| a.x * b.y - b.x * a.y).sum();
+    if (area > 0.0) != counter_clockwise {
+        coords[1..].reverse();
+    }
+
+    let mut ring = LineString(coords);
+    ring.close();
+    ring
+}
+
+/// Converts a `lyon` point into a `geo` coordinate.
+fn coord(point: lyon::math::Point) -> Coord<f64> {
+    Coord {
+        x: point.x as f64,
+        y: point.y as f64,
+    }
+}
+
+/// Appends a subpath of line segments through `coords`, if there are at least two of them.
+fn add_line_string(builder: &mut lyon::path::path::Builder, coords: &[Coord<f64>], close: bool) {
+    if coords.len() < 2 {
+        return;
+    }
+
+    let point = |c: &Coord<f64>| lyon::math::point(c.x as f32, c.y as f32);
+    builder.begin(point(&coords[0]));
+    for c in &coords[1..] {
+        builder.line_to(point(c));
+    }
+    builder.end(close);
+}
@@ -0,0 +1,125 @@
+//! Provides conversions to and from `kurbo` path types.
+//!
+//! This module allows for interoperability with the `kurbo` library, the curve library of
+//! the Druid, Xilem and Vello ecosystem, by converting between this crate's
+//! [`Path`](crate::path::Path) and `kurbo::BezPath`. It is only available with the `kurbo`
+//! feature.
+
+use kurbo::{BezPath, PathEl};
+use lyon::path::Event;
+
+use crate::path::point::{Point, PointConvert};
+
+/// Converts a reference to a [`Path`](crate::path::Path) into a `kurbo::BezPath`.
+///
+/// Every event maps to its `kurbo` counterpart: `Event::Begin` to `MoveTo`, `Event::Line`
+/// to `LineTo`, `Event::Quadratic` to `QuadTo`, and `Event::Cubic` to `CurveTo`. An
+/// `Event::End` of a closed subpath becomes a `ClosePath`; open subpaths simply end.
+///
+/// # Example
+///
+/// ```
+/// use kurbo::{BezPath, PathEl, Shape};
+/// use path_offset::path::Path;
+/// use std::str::FromStr;
+///
+/// let path = Path::from_str("M0,0 L10,0 Q10,10 0,10 Z M20,0 C20,5 25,10 30,10").unwrap();
+/// let bez_path = BezPath::from(&path);
+///
+/// assert_eq!(bez_path.elements().len(), 6);
+/// assert_eq!(bez_path.elements()[3], PathEl::ClosePath);
+/// assert_eq!(bez_path.bounding_box().max_x(), 30.0);
+///
+/// let round_trip = Path::from(&bez_path);
+/// assert_eq!(round_trip.to_string(), path.to_string());
+/// ```
+impl From<&crate::path::Path> for BezPath {
+    fn from(path: &crate::path::Path) -> BezPath {
+        let mut bez_path = BezPath::new();
+
+        for event in path.inner.iter() {
+            match event {
+                Event::Begin { at } => bez_path.move_to(at.use_as::<kurbo::Point>()),
+                Event::Line { to, .. } => bez_path.line_to(to.use_as::<kurbo::Point>()),
+                Event::Quadratic { ctrl, to, .. } => {
+                    bez_path.quad_to(ctrl.use_as::<kurbo::Point>(), to.use_as::<kurbo::Point>());
+                }
+                Event::Cubic {
+                    ctrl1, ctrl2, to, ..
+                } => bez_path.curve_to(
+                    ctrl1.use_as::<kurbo::Point>(),
+                    ctrl2.use_as::<kurbo::Point>(),
+                    to.use_as::<kurbo::Point>(),
+                ),
+                Event::End { close, .. } => {
+                    if close {
+                        bez_path.close_path();
+                    }
+                }
+            }
+        }
+
+        bez_path
+    }
+}
+
+/// Converts a reference to a `kurbo::BezPath` into a [`Path`](crate::path::Path).
+///
+/// Each `MoveTo` starts a new subpath, ending the previous one as open unless a `ClosePath`
+/// closed it. As in SVG, a segment following a `ClosePath` without a `MoveTo` starts a new
+/// subpath at the start point of the closed one.
+impl From<&BezPath> for crate::path::Path {
+    fn from(bez_path: &BezPath) -> Self {
+        let mut builder = lyon::path::Path::builder();
+        let mut start = lyon::math::Point::zero();
+        let mut in_subpath = false;
+
+        for element in bez_path.iter() {
+            // Segments need a subpath to extend.
+            if !in_subpath && !matches!(element, PathEl::MoveTo(_) | PathEl::ClosePath) {
+                builder.begin(start);
+                in_subpath = true;
+            }
+
+            match element {
+                PathEl::MoveTo(at) => {
+                    if in_subpath {
+                        builder.end(false);
+                    }
+                    start = Point::from(at).use_as();
+                    builder.begin(start);
+                    in_subpath = true;
+                }
+                PathEl::LineTo(to) => {
+                    builder.line_to(Point::from(to).use_as());
+                }
+                PathEl::QuadTo(ctrl, to) => {
+                    builder
+                        .quadratic_bezier_to(Point::from(ctrl).use_as(), Point::from(to).use_as());
+                }
+                PathEl::CurveTo(ctrl1, ctrl2, to) => {
+                    builder.cubic_bezier_to(
+                        Point::from(ctrl1).use_as(),
+                        Point::from(ctrl2).use_as(),
+                        Point::from(to).use_as(),
+                    );
+                }
+                PathEl::ClosePath => {
+                    if in_subpath {
+                        builder.end(true);
+                        in_subpath = false;
+                    }
+                }
+            }
+        }
+
+        if in_subpath {
+            builder.end(false);
+        }
+
+        Self {
+            inner: builder.build(),
+            scalars: None,
+        }
+    }
+}
@@ -11,6 +11,9 @@ use lyon::path::Path;
 /// straightforward conversion.
 impl From<Path> for crate::path::Path {
     fn from(value: Path) -> Self {
-        Self { inner: value }
+        Self {
+            inner: value,
+            scalars: None,
+        }
     }
 }
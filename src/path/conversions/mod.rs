@@ -7,8 +7,19 @@
 //! Currently supported libraries:
 //! - [`lyon`](lyon)
 //! - [`flo_curves`](flo_curves)
-//! - [`cavalier_contours`](cavalier_contours) (placeholder)
+//! - [`cavalier_contours`](cavalier_contours)
+//! - `kurbo`, with the `kurbo` feature
+//! - `geo-types`, as used by `geo`, with the `geo` feature
+//! - DXF entities, through the `dxf` crate, with the `dxf` feature
+//! - polygon rings (`Vec<Vec<[f64; 2]>>`), as used by triangulation libraries
 
 pub mod cavalier_contours;
+#[cfg(feature = "dxf")]
+pub mod dxf;
 pub mod flo_curves;
+#[cfg(feature = "geo")]
+pub mod geo;
+#[cfg(feature = "kurbo")]
+pub mod kurbo;
 pub mod lyon;
+pub mod rings;
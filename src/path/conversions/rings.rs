@@ -0,0 +1,130 @@
+//! Provides conversions between a [`Path`](crate::path::Path) and polygon rings.
+//!
+//! Triangulation and meshing libraries (e.g. earcut-style triangulators) describe a polygon
+//! as a list of rings, `Vec<Vec<[f64; 2]>>`, where the first ring is the exterior and every
+//! following ring is a hole. Rings are implicitly closed and do not repeat their first point.
+
+use crate::path::Path;
+
+impl Path {
+    /// Flattens the closed subpaths of the path into polygon rings, exterior first.
+    ///
+    /// The exterior is found with [`Path::find_outer_shell_index`]; the remaining closed
+    /// subpaths follow it as holes, in path order. Open subpaths do not bound an area and
+    /// are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `tolerance` - The maximum deviation allowed when flattening curves.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// // An annulus whose hole comes first in the path data.
+    /// let annulus = Path::from_str("M3,3 L3,7 L7,7 L7,3 Z M0,0 L10,0 L10,10 L0,10 Z").unwrap();
+    /// let rings = annulus.to_polygon_rings(0.01);
+    ///
+    /// assert_eq!(
+    ///     rings,
+    ///     [
+    ///         vec![[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 10.0]],
+    ///         vec![[3.0, 3.0], [3.0, 7.0], [7.0, 7.0], [7.0, 3.0]],
+    ///     ]
+    /// );
+    /// ```
+    pub fn to_polygon_rings(&self, tolerance: f32) -> Vec<Vec<[f64; 2]>> {
+        let closed: Path = self.iter().filter(Path::is_closed).collect();
+        let mut rings: Vec<Vec<[f64; 2]>> = closed
+            .polylines(tolerance)
+            .into_iter()
+            .map(|(points, _)| {
+                points
+                    .into_iter()
+                    .map(|p| [p.x as f64, p.y as f64])
+                    .collect()
+            })
+            .collect();
+
+        if let Some(index) = closed.find_outer_shell_index() {
+            let exterior = rings.remove(index);
+            rings.insert(0, exterior);
+        }
+
+        rings
+    }
+
+    /// Builds a closed multi-subpath path from polygon rings.
+    ///
+    /// Each ring becomes one closed subpath, in the given order. Holes are reversed where
+    /// needed so that they wind opposite to the exterior, which makes both the even-odd and
+    /// the nonzero fill rule render them as holes. A reversed hole keeps its first point.
+    /// Rings with fewer than two points are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `rings` - The rings of the polygon.
+    /// * `exterior_first` - Whether the first ring is the exterior, as produced by
+    ///   [`Path::to_polygon_rings`]. Otherwise the ring with the largest area is taken as
+    ///   the exterior.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// let annulus = Path::from_str("M0,0 L10,0 L10,10 L0,10 Z M3,3 L3,7 L7,7 L7,3 Z").unwrap();
+    /// let rings = annulus.to_polygon_rings(0.01);
+    /// assert_eq!(Path::from_polygon_rings(&rings, true).to_string(), annulus.to_string());
+    ///
+    /// // A hole that winds like the exterior is reversed.
+    /// let same_winding = [
+    ///     vec![[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 10.0]],
+    ///     vec![[3.0, 3.0], [7.0, 3.0], [7.0, 7.0], [3.0, 7.0]],
+    /// ];
+    /// let path = Path::from_polygon_rings(&same_winding, true);
+    /// assert_eq!(path.to_string(), "M0,0L10,0L10,10L0,10ZM3,3L3,7L7,7L7,3Z");
+    /// ```
+    pub fn from_polygon_rings(rings: &[Vec<[f64; 2]>], exterior_first: bool) -> Path {
+        let rings: Vec<&Vec<[f64; 2]>> = rings.iter().filter(|ring| ring.len() >= 2).collect();
+        let areas: Vec<f64> = rings.iter().map(|ring| ring_signed_area(ring)).collect();
+
+        let exterior = if exterior_first {
+            0
+        } else {
+            areas
+                .iter()
+                .enumerate()
+                .max_by(|(_, a), (_, b)| a.abs().total_cmp(&b.abs()))
+                .map_or(0, |(index, _)| index)
+        };
+        let exterior_positive = areas.get(exterior).is_some_and(|area| *area > 0.0);
+
+        let mut builder = lyon::path::Path::builder();
+        for (index, ring) in rings.iter().enumerate() {
+            let reverse = index != exterior && (areas[index] > 0.0) == exterior_positive;
+            let point = |[x, y]: [f64; 2]| lyon::math::point(x as f32, y as f32);
+
+            builder.begin(point(ring[0]));
+            let mut rest = ring[1..].to_vec();
+            if reverse {
+                rest.reverse();
+            }
+            for p in rest {
+                builder.line_to(point(p));
+            }
+            builder.close();
+        }
+
+        Path::from(builder.build())
+    }
+}
+
+/// Computes the signed area of a ring with the shoelace formula.
+fn ring_signed_area(ring: &[[f64; 2]]) -> f64 {
+    let edges = ring.iter().zip(ring.iter().cycle().skip(1));
+    edges.map(|(a, b)| a[0] * b[1] - b[0] * a[1]).sum::<f64>() / 2.0
+}
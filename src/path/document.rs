@@ -0,0 +1,95 @@
+//! Extracts paths from whole SVG documents with `usvg`.
+//!
+//! `usvg` resolves everything that makes raw SVG hard to read geometry from: basic shapes
+//! such as `<rect>`, `<circle>`, and `<polygon>` become paths, `<use>` references are
+//! expanded, styles and units are resolved, and every element gets its absolute transform.
+
+use usvg::{Group, Node, tiny_skia_path::PathSegment};
+
+use crate::error::Result;
+
+use super::{Builder, point::Point};
+
+impl super::Path {
+    /// Parses an SVG document and returns every shape it draws as a path.
+    ///
+    /// Each `<path>`, `<rect>`, `<circle>`, `<ellipse>`, `<line>`, `<polyline>`, and
+    /// `<polygon>` element becomes one path, in document order, with its own transform and
+    /// those of its ancestors applied. Coordinates are those of the rendered document,
+    /// so the `viewBox` is applied too. Shapes are extracted whether they are filled,
+    /// stroked, or neither, since their outlines matter even when they are not painted.
+    /// Elements that are never rendered are skipped, such as those inside `<defs>` and not
+    /// referenced by a `<use>`, or those with `display="none"`. Text and images are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PathError::Document`](crate::error::PathError::Document) if the document
+    /// cannot be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::Path;
+    ///
+    /// let svg = r#"
+    ///     <svg xmlns="http://www.w3.org/2000/svg" width="100" height="100">
+    ///         <path d="M0,0 L10,0 L10,10 Z"/>
+    ///         <g transform="translate(50, 0)">
+    ///             <rect x="0" y="0" width="20" height="10"/>
+    ///             <circle cx="10" cy="30" r="5" fill="none" stroke="black"/>
+    ///         </g>
+    ///         <rect width="5" height="5" fill="none"/>
+    ///     </svg>
+    /// "#;
+    /// let paths = Path::from_svg_document(svg).unwrap();
+    ///
+    /// assert_eq!(paths.len(), 4);
+    /// assert_eq!(paths[0].to_string(), "M0,0L10,0L10,10Z");
+    /// assert_eq!(paths[1].to_string(), "M50,0L70,0L70,10L50,10Z");
+    /// let (min, max) = paths[2].report(0.01).bounds.unwrap();
+    /// assert_eq!((min.0, min.1, max.0, max.1), (55.0, 25.0, 65.0, 35.0));
+    /// assert_eq!(paths[3].to_string(), "M0,0L5,0L5,5L0,5Z");
+    ///
+    /// assert!(Path::from_svg_document("<svg").is_err());
+    /// ```
+    pub fn from_svg_document(xml: &str) -> Result<Vec<super::Path>> {
+        let tree = usvg::Tree::from_str(xml, &usvg::Options::default())?;
+        let mut paths = Vec::new();
+        collect_paths(tree.root(), &mut paths);
+        Ok(paths)
+    }
+}
+
+/// Appends the paths drawn by a group and its descendants, in document order.
+fn collect_paths(group: &Group, paths: &mut Vec<super::Path>) {
+    for node in group.children() {
+        match node {
+            Node::Group(group) => collect_paths(group, paths),
+            Node::Path(path) => {
+                // Degenerate transforms, such as a zero scale, leave nothing to draw.
+                if let Some(data) = path.data().clone().transform(path.abs_transform()) {
+                    paths.push(from_segments(data.segments()));
+                }
+            }
+            Node::Image(_) | Node::Text(_) => {}
+        }
+    }
+}
+
+/// Builds a path from `tiny_skia` path segments.
+fn from_segments(segments: impl Iterator<Item = PathSegment>) -> super::Path {
+    let point = |p: usvg::tiny_skia_path::Point| Point(p.x as f64, p.y as f64);
+    let mut builder = Builder::new();
+    for segment in segments {
+        match segment {
+            PathSegment::MoveTo(at) => builder.begin(point(at)),
+            PathSegment::LineTo(to) => builder.line_to(point(to)),
+            PathSegment::QuadTo(ctrl, to) => builder.quadratic_to(point(ctrl), point(to)),
+            PathSegment::CubicTo(ctrl1, ctrl2, to) => {
+                builder.cubic_to(point(ctrl1), point(ctrl2), point(to))
+            }
+            PathSegment::Close => builder.close(),
+        };
+    }
+    builder.build()
+}
@@ -0,0 +1,241 @@
+//! Defines [`PathEvent`], this crate's view of a path's raw event stream.
+//!
+//! `lyon` describes paths as a stream of events carrying `f32` points and endpoint
+//! attributes. [`PathEvent`] mirrors that stream using the canonical [`Point`] type
+//! instead, so callers can walk a path's events without depending on `lyon` directly.
+//! [`Segment`] gives indexed access to the segments alone.
+
+use lyon::path::Event;
+
+use super::point::{Point, PointConvert};
+
+/// A single event in a path's event stream, expressed with canonical [`Point`]s.
+///
+/// Every subpath starts with a `Begin` event, followed by any number of segment events,
+/// and finishes with an `End` event.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PathEvent {
+    /// Starts a new subpath at `at`.
+    Begin { at: Point },
+    /// A straight line segment.
+    Line { from: Point, to: Point },
+    /// A quadratic Bézier segment with a single control point.
+    Quadratic { from: Point, ctrl: Point, to: Point },
+    /// A cubic Bézier segment with two control points.
+    Cubic {
+        from: Point,
+        ctrl1: Point,
+        ctrl2: Point,
+        to: Point,
+    },
+    /// Finishes the current subpath.
+    ///
+    /// `last` is the final point of the subpath and `first` the point it began at.
+    /// `close` is `true` if the subpath is closed back to `first`.
+    End {
+        last: Point,
+        first: Point,
+        close: bool,
+    },
+}
+
+/// A single segment of a path, expressed with canonical [`Point`]s.
+///
+/// Segments are what remains of the event stream without the events delimiting subpaths,
+/// so each one can be handled on its own, as in [`Path::segment`](super::Path::segment).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Segment {
+    /// A straight line segment.
+    Line { from: Point, to: Point },
+    /// A quadratic Bézier segment with a single control point.
+    Quadratic { from: Point, ctrl: Point, to: Point },
+    /// A cubic Bézier segment with two control points.
+    Cubic {
+        from: Point,
+        ctrl1: Point,
+        ctrl2: Point,
+        to: Point,
+    },
+}
+
+/// Converts a `lyon` path event into a [`PathEvent`].
+impl From<lyon::path::PathEvent> for PathEvent {
+    fn from(event: lyon::path::PathEvent) -> Self {
+        match event {
+            Event::Begin { at } => PathEvent::Begin { at: at.use_as() },
+            Event::Line { from, to } => PathEvent::Line {
+                from: from.use_as(),
+                to: to.use_as(),
+            },
+            Event::Quadratic { from, ctrl, to } => PathEvent::Quadratic {
+                from: from.use_as(),
+                ctrl: ctrl.use_as(),
+                to: to.use_as(),
+            },
+            Event::Cubic {
+                from,
+                ctrl1,
+                ctrl2,
+                to,
+            } => PathEvent::Cubic {
+                from: from.use_as(),
+                ctrl1: ctrl1.use_as(),
+                ctrl2: ctrl2.use_as(),
+                to: to.use_as(),
+            },
+            Event::End { last, first, close } => PathEvent::End {
+                last: last.use_as(),
+                first: first.use_as(),
+                close,
+            },
+        }
+    }
+}
+
+impl super::Path {
+    /// Returns an iterator over the raw events of the path.
+    ///
+    /// This is the lowest-level view of a path: unlike subpath iteration it exposes the
+    /// `Begin` and `End` events that delimit each subpath, with every coordinate converted
+    /// to the canonical [`Point`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::{Path, event::PathEvent, point::Point};
+    /// use std::str::FromStr;
+    ///
+    /// let square = Path::from_str("M0,0 L1,0 L1,1 L0,1 Z").unwrap();
+    /// let events: Vec<PathEvent> = square.events().collect();
+    ///
+    /// assert_eq!(
+    ///     events,
+    ///     [
+    ///         PathEvent::Begin { at: Point(0.0, 0.0) },
+    ///         PathEvent::Line { from: Point(0.0, 0.0), to: Point(1.0, 0.0) },
+    ///         PathEvent::Line { from: Point(1.0, 0.0), to: Point(1.0, 1.0) },
+    ///         PathEvent::Line { from: Point(1.0, 1.0), to: Point(0.0, 1.0) },
+    ///         PathEvent::End { last: Point(0.0, 1.0), first: Point(0.0, 0.0), close: true },
+    ///     ]
+    /// );
+    /// ```
+    pub fn events(&self) -> impl Iterator<Item = PathEvent> + '_ {
+        self.inner.iter().map(PathEvent::from)
+    }
+
+    /// Returns an iterator over the anchor points of the path.
+    ///
+    /// Each subpath yields its start point, then the endpoint of every segment in order.
+    /// Bézier control handles are excluded, and so is the implicit closing segment of a
+    /// closed subpath, which returns to a point already yielded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::{Path, point::Point};
+    /// use std::str::FromStr;
+    ///
+    /// let path = Path::from_str("M0,0 L10,0 Q10,10 0,10 Z M20,0 C20,5 25,10 30,10").unwrap();
+    /// let points: Vec<Point> = path.points().collect();
+    ///
+    /// assert_eq!(
+    ///     points,
+    ///     [
+    ///         Point(0.0, 0.0),
+    ///         Point(10.0, 0.0),
+    ///         Point(0.0, 10.0),
+    ///         Point(20.0, 0.0),
+    ///         Point(30.0, 10.0),
+    ///     ]
+    /// );
+    /// ```
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        self.events().filter_map(|event| match event {
+            PathEvent::Begin { at } => Some(at),
+            PathEvent::Line { to, .. }
+            | PathEvent::Quadratic { to, .. }
+            | PathEvent::Cubic { to, .. } => Some(to),
+            PathEvent::End { .. } => None,
+        })
+    }
+
+    /// Returns the number of segments in the path.
+    ///
+    /// Every line and curve counts as a segment, as does the closing line of a closed
+    /// subpath whose last point is not its first, which the event stream leaves implicit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// let path = Path::from_str("M0,0 L10,0 Q10,10 0,10 Z M20,0 L30,0").unwrap();
+    /// assert_eq!(path.segment_count(), 4);
+    /// ```
+    pub fn segment_count(&self) -> usize {
+        self.segments().count()
+    }
+
+    /// Returns the segment at `index`, counting segments across subpaths in path order.
+    ///
+    /// Segments are counted as in [`Path::segment_count`](super::Path::segment_count), so
+    /// the closing line of a closed subpath comes right after its last explicit segment.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::{Path, event::Segment, point::Point};
+    /// use std::str::FromStr;
+    ///
+    /// let path = Path::from_str("M0,0 L10,0 Q10,10 0,10 Z M20,0 L30,0").unwrap();
+    ///
+    /// assert_eq!(
+    ///     path.segment(1),
+    ///     Some(Segment::Quadratic {
+    ///         from: Point(10.0, 0.0),
+    ///         ctrl: Point(10.0, 10.0),
+    ///         to: Point(0.0, 10.0),
+    ///     })
+    /// );
+    /// // The closing line of the first subpath.
+    /// assert_eq!(
+    ///     path.segment(2),
+    ///     Some(Segment::Line { from: Point(0.0, 10.0), to: Point(0.0, 0.0) })
+    /// );
+    /// assert_eq!(
+    ///     path.segment(3),
+    ///     Some(Segment::Line { from: Point(20.0, 0.0), to: Point(30.0, 0.0) })
+    /// );
+    /// assert_eq!(path.segment(4), None);
+    /// ```
+    pub fn segment(&self, index: usize) -> Option<Segment> {
+        self.segments().nth(index)
+    }
+
+    /// Returns an iterator over the segments of the path, including implicit closing lines.
+    fn segments(&self) -> impl Iterator<Item = Segment> + '_ {
+        self.events().filter_map(|event| match event {
+            PathEvent::Begin { .. } => None,
+            PathEvent::Line { from, to } => Some(Segment::Line { from, to }),
+            PathEvent::Quadratic { from, ctrl, to } => Some(Segment::Quadratic { from, ctrl, to }),
+            PathEvent::Cubic {
+                from,
+                ctrl1,
+                ctrl2,
+                to,
+            } => Some(Segment::Cubic {
+                from,
+                ctrl1,
+                ctrl2,
+                to,
+            }),
+            PathEvent::End { last, first, close } => {
+                (close && last != first).then_some(Segment::Line {
+                    from: last,
+                    to: first,
+                })
+            }
+        })
+    }
+}
@@ -0,0 +1,96 @@
+//! Provides the convex hull of a path.
+//!
+//! Offsetting a convex hull gives a clearance boundary around a part, such as the region a
+//! tool has to stay out of. The hull is computed on the flattened geometry.
+
+use lyon::math::Point;
+
+impl super::Path {
+    /// Returns the convex hull of the path as a single closed subpath.
+    ///
+    /// Every subpath is flattened with `tolerance`, and the hull of the resulting vertices
+    /// is found with Andrew's monotone chain algorithm. Control points are not included:
+    /// they generally lie outside the curve they shape, and would make the hull larger than
+    /// the path. Curves bulging past their endpoints are covered by their flattened
+    /// vertices instead, to within `tolerance`.
+    ///
+    /// The hull runs counter-clockwise, with a positive
+    /// [`signed_area`](super::Path::signed_area), so a positive offset distance grows it.
+    /// Vertices lying on an edge of the hull are dropped. A path whose vertices are all
+    /// collinear gets a hull that runs along them and back, enclosing no area, and an
+    /// empty path gets an empty hull.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// // A five-pointed star.
+    /// let star = Path::from_str(
+    ///     "M50,0 L61,35 L98,35 L68,57 L79,91 L50,70 L21,91 L32,57 L2,35 L39,35 Z",
+    /// )
+    /// .unwrap();
+    /// let hull = star.convex_hull(0.01);
+    ///
+    /// // Only the five points of the star remain.
+    /// assert_eq!(hull.to_string(), "M2,35L50,0L98,35L79,91L21,91Z");
+    /// assert!(hull.signed_area(0.01) > star.signed_area(0.01).abs());
+    /// ```
+    pub fn convex_hull(&self, tolerance: f64) -> super::Path {
+        let mut points: Vec<Point> = self
+            .polylines(tolerance as f32)
+            .into_iter()
+            .flat_map(|(points, _)| points)
+            .collect();
+        points.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+        points.dedup();
+
+        let mut builder = lyon::path::Path::builder();
+        if let Some((first, rest)) = monotone_chain(&points).split_first() {
+            builder.begin(*first);
+            for point in rest {
+                builder.line_to(*point);
+            }
+            builder.end(true);
+        }
+
+        super::Path {
+            inner: builder.build(),
+            scalars: None,
+        }
+    }
+}
+
+/// Returns the vertices of the convex hull of sorted, deduplicated points, in the order in
+/// which a positive signed area visits them.
+fn monotone_chain(points: &[Point]) -> Vec<Point> {
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+
+    // Whether `c` turns strictly towards positive signed area from the segment `a → b`.
+    let turns = |a: Point, b: Point, c: Point| {
+        let (ab, ac) = ((b - a).to_f64(), (c - a).to_f64());
+        ab.cross(ac) > 0.0
+    };
+
+    // The lower chain from left to right, then the upper chain back from right to left,
+    // each dropping the vertices that do not turn the same way as the hull.
+    let mut hull: Vec<Point> = Vec::with_capacity(2 * points.len());
+    for pass in [points.to_vec(), points.iter().rev().copied().collect()] {
+        let start = hull.len();
+        for point in pass {
+            while hull.len() >= start + 2
+                && !turns(hull[hull.len() - 2], hull[hull.len() - 1], point)
+            {
+                hull.pop();
+            }
+            hull.push(point);
+        }
+        // The last point of each chain is the first of the other.
+        hull.pop();
+    }
+
+    hull
+}
@@ -0,0 +1,82 @@
+//! Provides self-intersection detection for paths.
+//!
+//! Offsetting inward frequently produces subpaths that cross themselves. This module
+//! locates those crossings on the flattened geometry so that users can debug input
+//! and validate offset output.
+
+use lyon::{geom::LineSegment, math::Point as LyonPoint};
+
+use super::point::{Point, PointConvert};
+
+impl super::Path {
+    /// Returns the points where a subpath crosses itself.
+    ///
+    /// Each subpath is flattened into line segments with `tolerance`, and every pair of
+    /// non-adjacent segments is tested for an intersection. Crossings that are closer
+    /// than `tolerance` to one another (for example a crossing exactly at a vertex,
+    /// which is found by several segment pairs) are reported once. Crossings between
+    /// two different subpaths are not reported.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// let figure_eight = Path::from_str("M0,0 L10,10 L10,0 L0,10 Z").unwrap();
+    /// let crossings = figure_eight.self_intersections(0.01);
+    ///
+    /// assert_eq!(crossings.len(), 1);
+    /// assert!((crossings[0].0 - 5.0).abs() < 1e-3);
+    /// assert!((crossings[0].1 - 5.0).abs() < 1e-3);
+    /// ```
+    pub fn self_intersections(&self, tolerance: f64) -> Vec<Point> {
+        let tolerance = tolerance as f32;
+        let mut crossings: Vec<LyonPoint> = Vec::new();
+
+        for (points, closed) in self.polylines(tolerance) {
+            let segments = polyline_segments(&points, closed);
+            let count = segments.len();
+
+            for i in 0..count {
+                for j in (i + 2)..count {
+                    // The first and last segments of a closed polyline share a vertex.
+                    if closed && i == 0 && j == count - 1 {
+                        continue;
+                    }
+
+                    if let Some(crossing) = segments[i].intersection(&segments[j]) {
+                        let is_new = crossings
+                            .iter()
+                            .all(|known| (*known - crossing).length() >= tolerance);
+                        if is_new {
+                            crossings.push(crossing);
+                        }
+                    }
+                }
+            }
+        }
+
+        crossings.iter().map(|p| p.use_as()).collect()
+    }
+}
+
+/// Returns the line segments of a polyline, including the closing segment if `closed`.
+pub(crate) fn polyline_segments(points: &[LyonPoint], closed: bool) -> Vec<LineSegment<f32>> {
+    let mut segments: Vec<_> = points
+        .windows(2)
+        .map(|pair| LineSegment {
+            from: pair[0],
+            to: pair[1],
+        })
+        .collect();
+
+    if closed && points.len() > 2 {
+        segments.push(LineSegment {
+            from: points[points.len() - 1],
+            to: points[0],
+        });
+    }
+
+    segments
+}
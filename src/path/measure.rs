@@ -0,0 +1,606 @@
+//! Provides measurements that compare a path against other geometry.
+//!
+//! Measurements are taken on the flattened path, so their accuracy is bounded by the
+//! flattening tolerance passed to each method. The centroid and the curvature are the
+//! exceptions: they are computed exactly on the curves.
+
+use lyon::{
+    geom::{CubicBezierSegment, LineSegment, QuadraticBezierSegment},
+    math::Point,
+    path::Event,
+};
+
+use super::{intersection::polyline_segments, point::PointConvert};
+
+impl super::Path {
+    /// Computes how far an offset result deviates from the expected offset distance.
+    ///
+    /// `samples` points are spread evenly by arc length along this path (the offset result).
+    /// For each of them, the distance to the nearest point of `source` is measured and
+    /// `|expected|` is subtracted, so a perfect offset yields a profile of zeros. Positive
+    /// values mean the result is too far from the source at that sample, negative values
+    /// that it is too close. This gives a richer picture of offset quality than a single
+    /// maximum error, since it shows where along the path the deviation occurs.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The path that was offset to produce this path.
+    /// * `expected` - The offset distance that was requested. Its sign is ignored.
+    /// * `samples` - The number of evenly spaced samples to take.
+    /// * `tolerance` - The flattening tolerance used for both paths.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<f64>` with one deviation per sample, in path order. It is empty if either
+    /// path has no segments.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// let square = Path::from_str("M0,0 L10,0 L10,10 L0,10 Z").unwrap();
+    /// // The exact outward offset by 1.0: straight edges joined by quarter circles.
+    /// let offset = Path::from_str(
+    ///     "M0,-1 L10,-1 A1,1 0 0 1 11,0 L11,10 A1,1 0 0 1 10,11 \
+    ///      L0,11 A1,1 0 0 1 -1,10 L-1,0 A1,1 0 0 1 0,-1 Z",
+    /// )
+    /// .unwrap();
+    ///
+    /// let profile = offset.offset_error_profile(&square, 1.0, 100, 0.001);
+    /// assert_eq!(profile.len(), 100);
+    /// assert!(profile.iter().all(|deviation| deviation.abs() < 0.01));
+    /// ```
+    pub fn offset_error_profile(
+        &self,
+        source: &super::Path,
+        expected: f64,
+        samples: usize,
+        tolerance: f32,
+    ) -> Vec<f64> {
+        let source_segments = source.segments_flattened(tolerance);
+        if source_segments.is_empty() {
+            return Vec::new();
+        }
+
+        self.sample_by_length(samples, tolerance)
+            .into_iter()
+            .map(|sample| {
+                let distance = source_segments
+                    .iter()
+                    .map(|segment| segment.distance_to_point(sample))
+                    .fold(f32::INFINITY, f32::min);
+                distance as f64 - expected.abs()
+            })
+            .collect()
+    }
+
+    /// Computes the total length of all subpaths.
+    ///
+    /// Curves are flattened to `tolerance` before their segments are measured. The length
+    /// of a closed subpath includes its closing segment back to its start point, so it is
+    /// the subpath's perimeter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// let path = Path::from_str("M0,0 L10,0 L10,10 L0,10 Z M20,0 L30,0").unwrap();
+    /// assert_eq!(path.length(0.01), 50.0);
+    ///
+    /// // The parser approximates the arcs with Bézier curves, which are then flattened.
+    /// let circle = Path::from_str("M10,0 A10,10 0 1 1 -10,0 A10,10 0 1 1 10,0 Z").unwrap();
+    /// assert!((circle.length(0.001) - 20.0 * std::f64::consts::PI).abs() < 0.2);
+    /// ```
+    pub fn length(&self, tolerance: f64) -> f64 {
+        self.segments_flattened(tolerance as f32)
+            .iter()
+            .map(|segment| segment.length() as f64)
+            .sum()
+    }
+
+    /// Returns points spaced evenly by arc length along each subpath.
+    ///
+    /// Each subpath is flattened with `tolerance` and walked from its start, placing a point
+    /// every `spacing` units of length, which suits stippling or dotted lines. Every subpath
+    /// starts over with a point at its start. An open subpath also gets a point at its end
+    /// when its length is a multiple of `spacing`; a closed subpath never repeats its start.
+    ///
+    /// # Arguments
+    ///
+    /// * `spacing` - The arc length between consecutive points. No points are returned if
+    ///   it is not positive.
+    /// * `tolerance` - The flattening tolerance used to walk curves.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::{Path, point::Point};
+    /// use std::str::FromStr;
+    ///
+    /// let line = Path::from_str("M0,0 L100,0").unwrap();
+    /// let points = line.sample_evenly(10.0, 0.01);
+    /// assert_eq!(points.len(), 11);
+    /// assert!(points[3].distance_to(Point(30.0, 0.0)) < 1e-4);
+    /// assert!(points[10].distance_to(Point(100.0, 0.0)) < 1e-4);
+    ///
+    /// let square = Path::from_str("M0,0 L10,0 L10,10 L0,10 Z").unwrap();
+    /// assert_eq!(square.sample_evenly(5.0, 0.01).len(), 8);
+    ///
+    /// // Points along a curve are spaced along the curve, not its chord.
+    /// let circle = Path::from_str("M10,0 A10,10 0 1 1 -10,0 A10,10 0 1 1 10,0 Z").unwrap();
+    /// let points = circle.sample_evenly(1.0, 0.001);
+    /// assert_eq!(points.len(), 63);
+    /// assert!(points.iter().all(|p| (p.distance_to(Point(0.0, 0.0)) - 10.0).abs() < 0.05));
+    /// ```
+    pub fn sample_evenly(&self, spacing: f64, tolerance: f64) -> Vec<super::point::Point> {
+        let mut samples = Vec::new();
+        if spacing <= 0.0 {
+            return samples;
+        }
+
+        for (points, closed) in self.polylines(tolerance as f32) {
+            let segments = polyline_segments(&points, closed);
+            let total: f64 = segments.iter().map(|s| s.length() as f64).sum();
+            let mut segments = segments.iter().peekable();
+            let mut segment_start = 0.0;
+
+            // A closed subpath ends where it started, which already has a point.
+            let count = (total / spacing).floor() as usize;
+            let count = if closed && count as f64 * spacing >= total {
+                count.saturating_sub(1)
+            } else {
+                count
+            };
+            for i in 0..=count {
+                let target = spacing * i as f64;
+                // Advance to the segment containing the target arc length.
+                while let Some(segment) =
+                    segments.next_if(|segment| segment_start + (segment.length() as f64) < target)
+                {
+                    segment_start += segment.length() as f64;
+                }
+
+                let sample = match segments.peek() {
+                    Some(segment) if segment.length() > 0.0 => {
+                        let t = (target - segment_start) / segment.length() as f64;
+                        segment.sample(t.clamp(0.0, 1.0) as f32)
+                    }
+                    Some(segment) => segment.from,
+                    None => points[points.len() - 1],
+                };
+                samples.push(sample.use_as());
+            }
+        }
+
+        samples
+    }
+
+    /// Computes the compactness of the path, `perimeter² / (4π·area)`.
+    ///
+    /// A circle has a compactness of `1.0`, the smallest possible value. Elongated or
+    /// ragged shapes score higher, which helps detect thin features that an offset may
+    /// collapse before choosing offset parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `tolerance` - The flattening tolerance used to measure curves.
+    ///
+    /// # Returns
+    ///
+    /// The compactness, or `None` if the path encloses no area.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// let circle = Path::from_str("M10,0 A10,10 0 1 1 -10,0 A10,10 0 1 1 10,0 Z").unwrap();
+    /// let thin = Path::from_str("M0,0 L100,0 L100,1 L0,1 Z").unwrap();
+    ///
+    /// assert!((circle.compactness(0.001).unwrap() - 1.0).abs() < 0.01);
+    /// assert!(thin.compactness(0.001).unwrap() > 30.0);
+    /// ```
+    pub fn compactness(&self, tolerance: f32) -> Option<f64> {
+        let area = self.approximate_signed_area(tolerance).abs() as f64;
+        if area <= f64::EPSILON {
+            return None;
+        }
+
+        let perimeter = self.length(tolerance as f64);
+
+        Some(perimeter * perimeter / (4.0 * std::f64::consts::PI * area))
+    }
+
+    /// Computes the area centroid of the closed subpaths of the path.
+    ///
+    /// The centroid is integrated exactly over the lines and Bézier curves of the outline
+    /// with Green's theorem, so no flattening tolerance is involved. Subpaths are weighted
+    /// by their signed area, as in [`signed_area`](super::Path::signed_area), so a hole
+    /// winding opposite to its shell is subtracted from it. Open subpaths are ignored.
+    ///
+    /// # Returns
+    ///
+    /// The centroid, or `None` if the closed subpaths enclose no area.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::{Path, point::Point};
+    /// use std::str::FromStr;
+    ///
+    /// let centroid = |svg: &str| Path::from_str(svg).unwrap().centroid().unwrap();
+    ///
+    /// let square = centroid("M0,0 L10,0 L10,10 L0,10 Z");
+    /// assert!(square.distance_to(Point(5.0, 5.0)) < 1e-9);
+    ///
+    /// // The centroid of a triangle is the average of its vertices.
+    /// let triangle = centroid("M0,0 L6,0 L0,3 Z");
+    /// assert!(triangle.distance_to(Point(2.0, 1.0)) < 1e-9);
+    ///
+    /// // A 2x2 hole centered at (7, 5) moves the centroid of a 10x10 square to the left.
+    /// let frame = centroid("M0,0 L10,0 L10,10 L0,10 Z M6,4 L6,6 L8,6 L8,4 Z");
+    /// assert!(frame.distance_to(Point((500.0 - 28.0) / 96.0, 5.0)) < 1e-9);
+    ///
+    /// // Curves are integrated exactly: a parabolic segment has its centroid two fifths of
+    /// // its height above its base.
+    /// let parabola = centroid("M0,0 Q5,10 10,0 Z");
+    /// assert!(parabola.distance_to(Point(5.0, 2.0)) < 1e-9);
+    ///
+    /// assert_eq!(Path::from_str("M0,0 L10,0 L10,10").unwrap().centroid(), None);
+    /// assert_eq!(Path::from_str("M0,0 L10,0 Z").unwrap().centroid(), None);
+    /// ```
+    pub fn centroid(&self) -> Option<super::point::Point> {
+        // Five-point Gauss–Legendre quadrature on [0, 1], which is exact for the degree 8
+        // polynomials integrated over cubic curves below.
+        const GAUSS: [(f64, f64); 5] = [
+            (0.046_910_077_030_668_004, 0.118_463_442_528_094_54),
+            (0.230_765_344_947_158_45, 0.239_314_335_249_683_26),
+            (0.5, 0.284_444_444_444_444_44),
+            (0.769_234_655_052_841_6, 0.239_314_335_249_683_26),
+            (0.953_089_922_969_332, 0.118_463_442_528_094_54),
+        ];
+
+        let (mut area, mut moment_x, mut moment_y) = (0.0, 0.0, 0.0);
+        for subpath in self.iter().filter(super::Path::is_closed) {
+            for event in subpath.inner.iter() {
+                // Curves are converted to `f64` before being raised to cubics, which keeps
+                // the integration exact.
+                let line = |from: Point, to: Point| {
+                    let (from, to) = (from.to_f64(), to.to_f64());
+                    QuadraticBezierSegment {
+                        from,
+                        ctrl: from.lerp(to, 0.5),
+                        to,
+                    }
+                    .to_cubic()
+                };
+                let curve = match event {
+                    Event::Begin { .. } => continue,
+                    Event::Line { from, to } => line(from, to),
+                    Event::End { last, first, .. } => line(last, first),
+                    Event::Quadratic { from, ctrl, to } => QuadraticBezierSegment {
+                        from: from.to_f64(),
+                        ctrl: ctrl.to_f64(),
+                        to: to.to_f64(),
+                    }
+                    .to_cubic(),
+                    Event::Cubic {
+                        from,
+                        ctrl1,
+                        ctrl2,
+                        to,
+                    } => CubicBezierSegment {
+                        from: from.to_f64(),
+                        ctrl1: ctrl1.to_f64(),
+                        ctrl2: ctrl2.to_f64(),
+                        to: to.to_f64(),
+                    },
+                };
+
+                // By Green's theorem, the area and its first moments are integrals along
+                // the outline.
+                for (t, weight) in GAUSS {
+                    let p = curve.sample(t);
+                    let d = curve.derivative(t);
+                    area += weight * (p.x * d.y - p.y * d.x) / 2.0;
+                    moment_x += weight * p.x * p.x * d.y / 2.0;
+                    moment_y -= weight * p.y * p.y * d.x / 2.0;
+                }
+            }
+        }
+
+        if area.abs() <= f64::EPSILON {
+            return None;
+        }
+        Some(super::point::Point(moment_x / area, moment_y / area))
+    }
+
+    /// Computes the signed curvature of a subpath at a normalized parameter.
+    ///
+    /// The segments of the subpath share the parameter range evenly: with `n` segments,
+    /// segment `i` spans `i / n` to `(i + 1) / n`, and its own Bézier parameter grows
+    /// linearly across that span. The closing segment of a closed subpath is counted as a
+    /// segment. A parameter falling on a vertex belongs to the segment that starts there,
+    /// except at `1`, which is the end of the last segment.
+    ///
+    /// The curvature is `1 / r` for the osculating circle of radius `r`, and `0` along
+    /// lines. It is positive where the path turns counter-clockwise with the y axis up
+    /// (clockwise as drawn in SVG, whose y axis points down), that is towards the left of
+    /// the direction of travel. Offsetting towards the centre of curvature by more than
+    /// `1 / |curvature|` folds the offset curve over itself, leaving a cusp or a loop.
+    ///
+    /// # Arguments
+    ///
+    /// * `subpath` - The index of the subpath, as in [`Path::subpath`](super::Path::subpath).
+    /// * `t` - The normalized parameter along the subpath, from `0` to `1`.
+    ///
+    /// # Returns
+    ///
+    /// The curvature, or `None` if the subpath does not exist, `t` is outside `0..=1`, or
+    /// the curve has no tangent at that point.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// // A counter-clockwise circle of radius 10, approximated by four cubic curves whose
+    /// // curvature strays by up to about 2% from that of the circle.
+    /// let k = 5.522847;
+    /// let circle = Path::from_str(&format!(
+    ///     "M10,0 C10,{k} {k},10 0,10 C-{k},10 -10,{k} -10,0 C-10,-{k} -{k},-10 0,-10 C{k},-10 10,-{k} 10,0 Z"
+    /// ))
+    /// .unwrap();
+    /// for step in 0..=8 {
+    ///     let curvature = circle.curvature_at(0, step as f64 / 8.0).unwrap();
+    ///     assert!((curvature - 0.1).abs() < 3e-3);
+    /// }
+    ///
+    /// // Reversing the path flips the sign.
+    /// let curvature = circle.reverse().curvature_at(0, 0.3).unwrap();
+    /// assert!((curvature + 0.1).abs() < 3e-3);
+    ///
+    /// // Lines, including the closing segment, are straight.
+    /// let path = Path::from_str("M0,0 L10,0 Q15,0 15,5 Z").unwrap();
+    /// assert_eq!(path.curvature_at(0, 0.2), Some(0.0));
+    /// assert_eq!(path.curvature_at(0, 0.9), Some(0.0));
+    /// assert!(path.curvature_at(0, 0.5).unwrap() > 0.0);
+    ///
+    /// assert_eq!(path.curvature_at(1, 0.5), None);
+    /// assert_eq!(path.curvature_at(0, 1.5), None);
+    /// ```
+    pub fn curvature_at(&self, subpath: usize, t: f64) -> Option<f64> {
+        if !(0.0..=1.0).contains(&t) {
+            return None;
+        }
+
+        let subpath = self.subpath(subpath)?;
+        // Every segment as a cubic curve, or `None` for lines.
+        let segments: Vec<Option<CubicBezierSegment<f64>>> = subpath
+            .inner
+            .iter()
+            .filter_map(|event| match event {
+                Event::Begin { .. } => None,
+                Event::Line { .. } => Some(None),
+                Event::End {
+                    last, first, close, ..
+                } => (close && last != first).then_some(None),
+                Event::Quadratic { from, ctrl, to } => Some(Some(
+                    QuadraticBezierSegment {
+                        from: from.to_f64(),
+                        ctrl: ctrl.to_f64(),
+                        to: to.to_f64(),
+                    }
+                    .to_cubic(),
+                )),
+                Event::Cubic {
+                    from,
+                    ctrl1,
+                    ctrl2,
+                    to,
+                } => Some(Some(CubicBezierSegment {
+                    from: from.to_f64(),
+                    ctrl1: ctrl1.to_f64(),
+                    ctrl2: ctrl2.to_f64(),
+                    to: to.to_f64(),
+                })),
+            })
+            .collect();
+
+        let position = t * segments.len() as f64;
+        let index = (position.floor() as usize).min(segments.len().checked_sub(1)?);
+        let Some(curve) = segments[index] else {
+            return Some(0.0);
+        };
+
+        let t = position - index as f64;
+        let d1 = curve.derivative(t);
+        let d2 = ((curve.ctrl2 - curve.ctrl1 * 2.0 + curve.from.to_vector()) * (1.0 - t)
+            + (curve.to - curve.ctrl2 * 2.0 + curve.ctrl1.to_vector()) * t)
+            * 6.0;
+        let speed = d1.length();
+        if speed <= f64::EPSILON {
+            return None;
+        }
+        Some(d1.cross(d2) / (speed * speed * speed))
+    }
+
+    /// Approximates the largest circle that fits inside the closed subpaths of the path.
+    ///
+    /// The centre is the pole of inaccessibility, the interior point farthest from the
+    /// outline, which is a good anchor for placing a label inside an irregular shape. It is
+    /// found with a quadtree search over the bounding box: cells that cannot contain a point
+    /// farther from the outline than the best one so far are discarded, and the others are
+    /// split until the remaining potential gain is below `tolerance`. Holes are respected
+    /// using the even-odd rule; open subpaths are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `tolerance` - The precision of the result, also used to flatten curves.
+    ///
+    /// # Returns
+    ///
+    /// The centre and radius of the circle, or `None` if the path encloses no area.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// let square = Path::from_str("M0,0 L10,0 L10,10 L0,10 Z").unwrap();
+    /// let (center, radius) = square.inscribed_circle(0.01).unwrap();
+    ///
+    /// assert!((center.0 - 5.0).abs() < 0.1 && (center.1 - 5.0).abs() < 0.1);
+    /// assert!((radius - 5.0).abs() < 0.01);
+    /// ```
+    pub fn inscribed_circle(&self, tolerance: f64) -> Option<(super::point::Point, f64)> {
+        let outline: super::Path = self.iter().filter(super::Path::is_closed).collect();
+        let segments = outline.segments_flattened(tolerance as f32);
+        let bbox = lyon::algorithms::aabb::bounding_box(outline.inner.iter());
+        let cell_size = bbox.width().min(bbox.height()) as f64;
+        if segments.is_empty() || cell_size <= 0.0 {
+            return None;
+        }
+
+        // The distance from a point to the outline, negative outside of the path.
+        let signed_distance = |x: f64, y: f64| {
+            let p = lyon::math::point(x as f32, y as f32);
+            let distance = segments
+                .iter()
+                .map(|segment| segment.distance_to_point(p))
+                .fold(f32::INFINITY, f32::min) as f64;
+            let inside = lyon::algorithms::hit_test::hit_test_path(
+                &p,
+                outline.inner.iter(),
+                lyon::path::FillRule::EvenOdd,
+                tolerance as f32,
+            );
+            if inside { distance } else { -distance }
+        };
+        let cell = |x: f64, y: f64, half: f64| Cell {
+            x,
+            y,
+            half,
+            distance: signed_distance(x, y),
+        };
+
+        let mut queue = std::collections::BinaryHeap::new();
+        let half = cell_size / 2.0;
+        let mut y = bbox.min.y as f64;
+        while y < bbox.max.y as f64 {
+            let mut x = bbox.min.x as f64;
+            while x < bbox.max.x as f64 {
+                queue.push(cell(x + half, y + half, half));
+                x += cell_size;
+            }
+            y += cell_size;
+        }
+
+        let center = bbox.center();
+        let mut best = cell(center.x as f64, center.y as f64, 0.0);
+        while let Some(current) = queue.pop() {
+            if current.distance > best.distance {
+                best = current;
+            }
+            if current.potential() - best.distance <= tolerance {
+                continue;
+            }
+
+            let half = current.half / 2.0;
+            for (dx, dy) in [(-1.0, -1.0), (1.0, -1.0), (-1.0, 1.0), (1.0, 1.0)] {
+                queue.push(cell(current.x + dx * half, current.y + dy * half, half));
+            }
+        }
+
+        (best.distance > 0.0).then_some((super::point::Point(best.x, best.y), best.distance))
+    }
+
+    /// Flattens every subpath and returns all of their line segments, including closing ones.
+    pub(crate) fn segments_flattened(&self, tolerance: f32) -> Vec<LineSegment<f32>> {
+        self.polylines(tolerance)
+            .iter()
+            .flat_map(|(points, closed)| polyline_segments(points, *closed))
+            .collect()
+    }
+
+    /// Returns `count` points spread evenly by arc length along the flattened path.
+    ///
+    /// The subpaths are walked one after another as if they were a single polyline.
+    pub(crate) fn sample_by_length(&self, count: usize, tolerance: f32) -> Vec<Point> {
+        let segments = self.segments_flattened(tolerance);
+        let total_length: f32 = segments.iter().map(LineSegment::length).sum();
+        if count == 0 || segments.is_empty() {
+            return Vec::new();
+        }
+
+        let spacing = total_length / count as f32;
+        let mut samples = Vec::with_capacity(count);
+        let mut index = 0;
+        let mut segment_start = 0.0;
+
+        for i in 0..count {
+            let target = spacing * i as f32;
+            // Advance to the segment containing the target arc length.
+            while index + 1 < segments.len() && segment_start + segments[index].length() < target {
+                segment_start += segments[index].length();
+                index += 1;
+            }
+
+            let segment = &segments[index];
+            let length = segment.length();
+            let t = if length > 0.0 {
+                ((target - segment_start) / length).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            samples.push(segment.sample(t));
+        }
+
+        samples
+    }
+}
+
+/// A square cell of the search in [`Path::inscribed_circle`](super::Path::inscribed_circle).
+#[derive(Clone, Copy)]
+struct Cell {
+    x: f64,
+    y: f64,
+    half: f64,
+    /// The signed distance from the cell centre to the outline.
+    distance: f64,
+}
+
+impl Cell {
+    /// The largest distance to the outline any point inside the cell can have.
+    fn potential(&self) -> f64 {
+        self.distance + self.half * std::f64::consts::SQRT_2
+    }
+}
+
+/// Cells are ordered by their potential, so the most promising one is searched first.
+impl Ord for Cell {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.potential().total_cmp(&other.potential())
+    }
+}
+
+impl PartialOrd for Cell {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Cell {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl Eq for Cell {}
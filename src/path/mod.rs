@@ -3,34 +3,192 @@
 //! This module provides the core `Path` struct, which represents a geometric path,
 //! and includes functionality for parsing, manipulating, and iterating over paths.
 
-use std::{fmt::Display, str::FromStr};
+use std::{
+    fmt::Display,
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
 
 use lyon::path::Event;
 
-use crate::error::PathError;
+use crate::{error::PathError, path::point::PointConvert};
 
+/// The rule deciding which points are inside a path whose subpaths overlap, re-exported from
+/// `lyon` for [`Path::contains_point`].
+pub use lyon::path::FillRule;
+
+pub use builder::Builder;
+pub use svg::to_svg_document;
+
+mod arithmetic;
+mod builder;
+mod compose;
+pub mod contour;
 pub mod conversions;
+#[cfg(feature = "usvg")]
+mod document;
+pub mod event;
+mod hull;
+mod intersection;
+mod measure;
 pub mod point;
+mod reorder;
+pub mod report;
+mod scalars;
+#[cfg(feature = "serde")]
+mod serde;
+mod simplify;
+mod subdivide;
 pub mod subpath;
+mod svg;
+pub mod transform;
+mod validate;
+mod winding;
 
 /// Represents a geometric path, composed of one or more subpaths.
 ///
 /// A `Path` can be created from an SVG path string and can be iterated over
 /// to process its individual subpaths. It also provides utilities for
 /// analyzing path properties, such as finding the outermost contour.
+///
+/// # Precision
+///
+/// Coordinates are stored as `f32`, the coordinate type of `lyon` paths, even though the
+/// crate's API takes and returns `f64` [`Point`](point::Point)s and the offset backends
+/// compute in `f64`. Every time a path is created, whether parsed, built, or produced by
+/// an offset, its coordinates are rounded to the nearest `f32`. That keeps about seven
+/// significant digits: near the origin the rounding is negligible, but at a million units
+/// from it the coordinates snap to steps of 1/16 of a unit. Data with large coordinates,
+/// as in CAD drawings placed in world coordinates, should be translated near the origin
+/// before offsetting by small distances, and translated back afterwards.
+///
+/// # Example
+///
+/// ```
+/// use path_offset::{Backend, offset, path::Path};
+/// use std::str::FromStr;
+///
+/// let left_edge = |origin: f64| {
+///     let square = Path::from_str(&format!(
+///         "M{a},{a} L{b},{a} L{b},{b} L{a},{b} Z",
+///         a = origin,
+///         b = origin + 10.0
+///     ))
+///     .unwrap();
+///     let grown = offset(&square, 0.03, Backend::CavalierContours).unwrap();
+///     grown.report(0.001).bounds.unwrap().0.0 - origin
+/// };
+///
+/// // Near the origin, the square grows by the offset distance.
+/// assert!((left_edge(0.0) + 0.03).abs() < 1e-6);
+/// // A million units away, the offset is rounded away entirely.
+/// assert_eq!(left_edge(1e6), 0.0);
+///
+/// // Parsing and serializing alone round the coordinates too.
+/// let far = Path::from_str("M1000000.03,0 L1000010.03,0").unwrap();
+/// assert_eq!(far.to_string(), "M1000000,0L1000010,0");
+/// ```
 #[derive(Debug, Clone)]
 pub struct Path {
     inner: lyon::path::Path,
+    /// An optional scalar value per vertex, in event order (see [`Path::with_scalars`]).
+    scalars: Option<Vec<f64>>,
 }
 
 impl Path {
     /// Returns an iterator over the subpaths of this path.
     ///
-    /// Each item in the iterator is a `Path` representing a single subpath.
-    pub fn iter(&self) -> impl Iterator<Item = Path> + '_ {
+    /// Each item in the iterator is a `Path` representing a single subpath. Every subpath
+    /// keeps its own closedness, so [`Path::is_closed`] tells closed and open ones apart.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// let path = Path::from_str("M0,0 L10,0 L10,10 Z M20,0 L30,0 M40,0 L50,0 L50,10 Z").unwrap();
+    /// let closed: Vec<bool> = path.iter().map(|subpath| subpath.is_closed()).collect();
+    ///
+    /// assert_eq!(closed, [true, false, true]);
+    /// ```
+    pub fn iter(&self) -> subpath::SubpathIter<'_> {
         self.into_iter()
     }
 
+    /// Returns the subpath at `index`, in the order [`Path::iter`] yields them.
+    ///
+    /// This walks the event stream once to locate the subpath, then builds only that one.
+    /// To visit several subpaths, iterate instead: the iterator locates them all up front
+    /// and can be advanced with `nth` or from the back without rebuilding earlier ones.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// let path = Path::from_str("M0,0 L10,0 M20,0 L30,0 M40,0 L50,0").unwrap();
+    /// assert_eq!(path.subpath(1).unwrap().to_string(), "M20,0L30,0");
+    /// assert!(path.subpath(3).is_none());
+    ///
+    /// // The iterator works from both ends.
+    /// let last_first: Vec<String> = path.iter().rev().map(|subpath| subpath.to_string()).collect();
+    /// assert_eq!(last_first, ["M40,0L50,0", "M20,0L30,0", "M0,0L10,0"]);
+    /// ```
+    pub fn subpath(&self, index: usize) -> Option<Path> {
+        self.iter().nth(index)
+    }
+
+    /// Returns the number of subpaths [`Path::iter`] yields, without building them.
+    ///
+    /// Like the iterator, this skips empty subpaths made of a lone moveto. It walks the
+    /// event stream once and allocates nothing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// let path = Path::from_str("M0,0 L10,0 L10,10 Z M20,0 L30,0 M40,0").unwrap();
+    /// assert_eq!(path.subpath_count(), 2);
+    /// assert_eq!(path.subpath_count(), path.iter().count());
+    /// ```
+    pub fn subpath_count(&self) -> usize {
+        let mut count = 0;
+        let mut has_segments = false;
+        for event in self.inner.iter() {
+            match event {
+                // A subpath ends at its `End` event, or at the next `Begin` if it has none.
+                Event::Begin { .. } | Event::End { .. } => {
+                    count += usize::from(has_segments);
+                    has_segments = false;
+                }
+                _ => has_segments = true,
+            }
+        }
+        count + usize::from(has_segments)
+    }
+
+    /// Checks if the path has no events at all.
+    ///
+    /// A path made of a lone moveto is not empty, even though it has no subpaths to
+    /// iterate over.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// assert!(Path::from_str("").unwrap().is_empty());
+    /// assert!(!Path::from_str("M0,0 L10,0").unwrap().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.inner.iter().next().is_none()
+    }
+
     /// Checks if the path is closed.
     ///
     /// A path is considered closed if it ends with a `Close` event.
@@ -40,16 +198,234 @@ impl Path {
             .any(|e| matches!(e, Event::End { close: true, .. }))
     }
 
+    /// Computes the signed area enclosed by the path.
+    ///
+    /// The area is positive for subpaths running counter-clockwise with the y axis pointing
+    /// up, which is clockwise on screen with the y axis pointing down, as in SVG. Subpaths
+    /// winding the other way contribute a negative area, so the sign tells shells and holes
+    /// apart, and a positive offset distance grows subpaths with a positive area. The areas
+    /// of all subpaths are summed. Only closed subpaths enclose a meaningful area.
+    ///
+    /// Curves are flattened with the given `tolerance` before the area is accumulated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// let square = Path::from_str("M0,0 L10,0 L10,10 L0,10 Z").unwrap();
+    /// assert_eq!(square.signed_area(0.01), 100.0);
+    ///
+    /// let reversed = Path::from_str("M0,0 L0,10 L10,10 L10,0 Z").unwrap();
+    /// assert_eq!(reversed.signed_area(0.01), -100.0);
+    /// ```
+    pub fn signed_area(&self, tolerance: f64) -> f64 {
+        self.approximate_signed_area(tolerance as f32) as f64
+    }
+
+    /// Computes the approximate signed area enclosed by the path.
+    ///
+    /// Curves are flattened with the given `tolerance` before the area is accumulated.
+    pub(crate) fn approximate_signed_area(&self, tolerance: f32) -> f32 {
+        lyon::algorithms::area::approximate_signed_area(tolerance, self.inner.iter())
+    }
+
+    /// Flattens the path into one polyline per subpath, paired with its closedness.
+    ///
+    /// Closed polylines do not repeat their first point at the end, and consecutive
+    /// duplicate points are dropped.
+    pub(crate) fn polylines(&self, tolerance: f32) -> Vec<(Vec<lyon::math::Point>, bool)> {
+        self.flattened_subpaths(tolerance)
+            .into_iter()
+            .map(|(points, _, closed)| (points, closed))
+            .collect()
+    }
+
+    /// Flattens the path like [`Path::polylines`], also returning where each point came from.
+    ///
+    /// Each point is paired with a fractional vertex index: a point a quarter of the way
+    /// along the segment from vertex 3 to vertex 4 has the index `3.25`. This allows
+    /// per-vertex data to be interpolated onto the flattened points.
+    pub(crate) fn flattened_subpaths(
+        &self,
+        tolerance: f32,
+    ) -> Vec<(Vec<lyon::math::Point>, Vec<f64>, bool)> {
+        let mut subpaths = Vec::new();
+        let mut points = Vec::new();
+        let mut params: Vec<f64> = Vec::new();
+        // The index of the vertex the current segment starts at.
+        let mut vertex = 0.0;
+
+        fn push(
+            points: &mut Vec<lyon::math::Point>,
+            params: &mut Vec<f64>,
+            point: lyon::math::Point,
+            param: f64,
+        ) {
+            if points.last() != Some(&point) {
+                points.push(point);
+                params.push(param);
+            }
+        }
+
+        for event in self.inner.iter() {
+            match event {
+                Event::Begin { at } => {
+                    points = vec![at];
+                    params = vec![vertex];
+                }
+                Event::Line { to, .. } => {
+                    push(&mut points, &mut params, to, vertex + 1.0);
+                    vertex += 1.0;
+                }
+                Event::Quadratic { from, ctrl, to } => {
+                    let curve = lyon::geom::QuadraticBezierSegment { from, ctrl, to };
+                    curve.for_each_flattened_with_t(tolerance, &mut |line, t| {
+                        push(&mut points, &mut params, line.to, vertex + t.end as f64);
+                    });
+                    vertex += 1.0;
+                }
+                Event::Cubic {
+                    from,
+                    ctrl1,
+                    ctrl2,
+                    to,
+                } => {
+                    let curve = lyon::geom::CubicBezierSegment {
+                        from,
+                        ctrl1,
+                        ctrl2,
+                        to,
+                    };
+                    curve.for_each_flattened_with_t(tolerance, &mut |line, t| {
+                        push(&mut points, &mut params, line.to, vertex + t.end as f64);
+                    });
+                    vertex += 1.0;
+                }
+                Event::End { first, close, .. } => {
+                    if close && points.len() > 1 && points.last() == Some(&first) {
+                        points.pop();
+                        params.pop();
+                    }
+                    subpaths.push((
+                        std::mem::take(&mut points),
+                        std::mem::take(&mut params),
+                        close,
+                    ));
+                    // The next subpath begins at a new vertex.
+                    vertex += 1.0;
+                }
+            }
+        }
+
+        subpaths
+    }
+
     /// Find and return the subpath that represents the outermost shell.
     ///
     /// This method first attempts to use a fast "largest area" heuristic.
     /// If that fails to produce a result, it falls back to a more accurate but slower
     /// "geometric containment" algorithm.
     ///
+    /// The shell is the largest subpath, by absolute area, among those not contained by any
+    /// other. When the path holds several disjoint shapes, each with its own holes, that is
+    /// the shell of the largest shape. Containment follows the even-odd rule; see
+    /// [`Path::find_outer_shell_with_fill_rule`] for paths meant to be filled with the
+    /// nonzero rule.
+    ///
     /// # Returns
     ///
     /// An `Option<Path>` containing the outermost shell if found, otherwise `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// // Two disjoint squares winding in opposite directions.
+    /// let path = Path::from_str("M0,0 L4,0 L4,4 L0,4 Z M10,0 L10,10 L20,10 L20,0 Z").unwrap();
+    /// let shell = path.find_outer_shell().unwrap();
+    ///
+    /// assert_eq!(shell.to_string(), "M10,0L10,10L20,10L20,0Z");
+    /// ```
     pub fn find_outer_shell(&self) -> Option<Path> {
+        self.find_outer_shell_with_fill_rule(FillRule::EvenOdd)
+    }
+
+    /// Find and return the outermost shell, testing containment with the given fill rule.
+    ///
+    /// The fill rule decides which regions of the path are inside, as in
+    /// [`Path::contains_point`], and so which subpaths contain the others. The two rules
+    /// only disagree where the outline covers a region more than once, as overlapping
+    /// contours in fonts often do: even-odd treats a region covered twice as outside, so a
+    /// subpath lying there is not contained, while nonzero treats it as inside as long as
+    /// the coverings run the same way.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::{FillRule, Path};
+    /// use std::str::FromStr;
+    ///
+    /// // An outline running twice around the square from (0, 0) to (10, 10), then once the
+    /// // other way around a lobe to its left, and a smaller square inside the first one.
+    /// let path = Path::from_str(
+    ///     "M0,0 L10,0 L10,10 L0,10 L0,0 L10,0 L10,10 L0,10 L0,0 L-19,0 L-19,10 L0,10 Z \
+    ///      M3,3 L8,3 L8,8 L3,8 Z",
+    /// )
+    /// .unwrap();
+    ///
+    /// // With the nonzero rule, the square covered twice is filled and the outline contains
+    /// // the smaller square. With the even-odd rule, it is empty and the smaller square
+    /// // stands on its own, with a larger area than the outline, whose two loops cancel out.
+    /// let nonzero = path.find_outer_shell_with_fill_rule(FillRule::NonZero).unwrap();
+    /// assert_eq!(nonzero.to_string(), path.subpath(0).unwrap().to_string());
+    /// let even_odd = path.find_outer_shell_with_fill_rule(FillRule::EvenOdd).unwrap();
+    /// assert_eq!(even_odd.to_string(), "M3,3L8,3L8,8L3,8Z");
+    /// ```
+    pub fn find_outer_shell_with_fill_rule(&self, fill_rule: FillRule) -> Option<Path> {
+        self.subpath(self.outer_shell_index(fill_rule)?)
+    }
+
+    /// Find the index of the subpath that represents the outermost shell.
+    ///
+    /// The shell is chosen as in [`Path::find_outer_shell`], with the even-odd rule. The
+    /// index counts subpaths in the order of [`Path::iter`], as [`Path::subpath`] does, so
+    /// the shell can be told apart from the other subpaths, for example to offset it
+    /// separately from its holes.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<usize>` containing the index of the outermost shell if found, otherwise
+    /// `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// // The hole comes before its shell.
+    /// let path = Path::from_str("M4,4 L4,6 L6,6 L6,4 Z M0,0 L10,0 L10,10 L0,10 Z").unwrap();
+    /// let index = path.find_outer_shell_index().unwrap();
+    ///
+    /// assert_eq!(index, 1);
+    /// let holes: Vec<Path> = path
+    ///     .iter()
+    ///     .enumerate()
+    ///     .filter_map(|(i, subpath)| (i != index).then_some(subpath))
+    ///     .collect();
+    /// assert_eq!(holes.len(), 1);
+    /// assert_eq!(holes[0].to_string(), "M4,4L4,6L6,6L6,4Z");
+    /// ```
+    pub fn find_outer_shell_index(&self) -> Option<usize> {
+        self.outer_shell_index(FillRule::EvenOdd)
+    }
+
+    /// Finds the index of the outermost shell, testing containment with the fill rule.
+    fn outer_shell_index(&self, fill_rule: FillRule) -> Option<usize> {
         let subpaths: Vec<Path> = self.iter().collect();
 
         match subpaths.len() {
@@ -57,42 +433,213 @@ impl Path {
             0 => None,
 
             // Case 2: Only one subpath, which is the shell by definition.
-            // We use .into_iter().next() to consume the Vec and take the single element
-            // without needing to clone it.
-            1 => subpaths.into_iter().next(),
+            1 => Some(0),
 
             // Case 3: Multiple subpaths, execute the "smart" finding logic.
             _ => {
                 // First, try the fast area heuristic.
-                find_shell_by_area(&subpaths)
+                find_shell_by_area(&subpaths, fill_rule)
                     // If the area method returns nothing, fall back to the precise geometric containment algorithm.
-                    .or_else(|| find_shell_by_containment(&subpaths))
+                    .or_else(|| find_shell_by_containment(&subpaths, fill_rule))
             }
         }
     }
 
+    /// Removes closed subpaths whose absolute area is below `min_area`.
+    ///
+    /// Offsetting and cleaning can leave tiny spurious loops behind; this drops them
+    /// while keeping every other subpath in its original order. Open subpaths enclose
+    /// no area and are always kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_area` - The smallest absolute area a closed subpath must have to be kept.
+    /// * `tolerance` - The flattening tolerance used when computing the area of curves.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// let path = Path::from_str("M0,0 L10,0 L10,10 L0,10 Z M20,20 L20.5,20 L20,20.5 Z").unwrap();
+    /// let cleaned = path.remove_small_subpaths(1.0, 0.01);
+    ///
+    /// assert_eq!(cleaned.subpath_count(), 1);
+    /// assert_eq!(cleaned.to_string(), "M0,0L10,0L10,10L0,10Z");
+    /// ```
+    pub fn remove_small_subpaths(&self, min_area: f32, tolerance: f32) -> Path {
+        self.iter()
+            .filter(|subpath| {
+                !subpath.is_closed() || subpath.approximate_signed_area(tolerance).abs() >= min_area
+            })
+            .collect()
+    }
+
+    /// Removes the closed subpaths too small to be machined, such as the thin slivers and
+    /// tiny islands an inward offset can leave behind.
+    ///
+    /// A closed subpath is dropped if the absolute value of its
+    /// [`signed_area`](Path::signed_area) is below `min_area`, with curves flattened to
+    /// within `0.01`. Unlike [`Path::remove_small_subpaths`], it takes the area as `f64`,
+    /// like [`Path::signed_area`] returns it, and needs no flattening tolerance. Open
+    /// subpaths enclose no area and are always kept, and the remaining subpaths keep their
+    /// order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::offset::{Offset, flo_curves::FloCurvesOffset};
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// let path = Path::from_str("M0,0 L10,0 L10,10 L0,10 Z M20,20 L20.5,20 L20,20.5 Z").unwrap();
+    /// let kept = path.drop_small_features(1.0);
+    ///
+    /// assert_eq!(kept.subpath_count(), 1);
+    /// assert_eq!(kept.to_string(), "M0,0L10,0L10,10L0,10Z");
+    ///
+    /// // Shrinking a dumbbell leaves debris loops around its two lobes.
+    /// let dumbbell = Path::from_str(
+    ///     "M0,0 L10,0 L10,4 L20,4 L20,0 L30,0 L30,10 L20,10 L20,6 L10,6 L10,10 L0,10 Z",
+    /// )
+    /// .unwrap();
+    /// let pocket = FloCurvesOffset::new(-1.5).offset_path(&dumbbell).unwrap();
+    /// assert!(pocket.subpath_count() > 2);
+    /// assert_eq!(pocket.drop_small_features(30.0).subpath_count(), 2);
+    /// ```
+    pub fn drop_small_features(&self, min_area: f64) -> Path {
+        self.iter()
+            .filter(|subpath| !subpath.is_closed() || subpath.signed_area(0.01).abs() >= min_area)
+            .collect()
+    }
+
+    /// Returns the axis-aligned bounding box of the path as its min and max corners.
+    ///
+    /// The box is computed from every endpoint and control point with `lyon`'s fast
+    /// bounding box. It always encloses the path, but may be loose around curves, whose
+    /// control points usually lie outside the curve itself. Returns `None` for an empty path.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::{Path, point::Point};
+    /// use std::str::FromStr;
+    ///
+    /// let path = Path::from_str("M0,0 L10,0 L10,10 Z M20,5 L30,5").unwrap();
+    /// assert_eq!(path.bounding_box(), Some((Point(0.0, 0.0), Point(30.0, 10.0))));
+    ///
+    /// // The control point of the curve extends the box past its peak at y = 5.
+    /// let arch = Path::from_str("M0,0 Q5,10 10,0").unwrap();
+    /// assert_eq!(arch.bounding_box(), Some((Point(0.0, 0.0), Point(10.0, 10.0))));
+    ///
+    /// assert_eq!(Path::from_str("").unwrap().bounding_box(), None);
+    /// ```
+    pub fn bounding_box(&self) -> Option<(point::Point, point::Point)> {
+        self.inner.iter().next()?;
+
+        let bbox = lyon::algorithms::aabb::fast_bounding_box(self.inner.iter());
+        Some((bbox.min.use_as(), bbox.max.use_as()))
+    }
+
     /// Checks if this path's bounding box intersects with another path's bounding box.
-    fn intersect_with(&self, other: &Path) -> bool {
+    ///
+    /// This is a cheap conservative test: paths whose boxes do not overlap cannot
+    /// intersect, but overlapping boxes do not imply that the paths themselves do.
+    pub fn bbox_intersects(&self, other: &Path) -> bool {
         let bbox_a = lyon::algorithms::aabb::bounding_box(self.inner.iter());
         let bbox_b = lyon::algorithms::aabb::bounding_box(other.inner.iter());
         bbox_a.intersects(&bbox_b)
     }
 
-    /// Checks if this path is geometrically contained within another path.
-    fn contained_by(&self, other_path: &Path) -> bool {
+    /// Checks if a point lies within the bounding box of this path, boundary included.
+    ///
+    /// This is far cheaper than a full hit test, which has to walk every segment of the
+    /// path, and it rejects most points that are nowhere near the path. A `true` result
+    /// only means the point may be inside the path.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::{Path, point::Point};
+    /// use std::str::FromStr;
+    ///
+    /// // A polygon with many vertices, where a full hit test is comparatively costly.
+    /// let svg = (0..1000)
+    ///     .map(|i| {
+    ///         let angle = i as f64 * std::f64::consts::TAU / 1000.0;
+    ///         format!("L{},{} ", 50.0 * angle.cos(), 50.0 * angle.sin())
+    ///     })
+    ///     .collect::<String>()
+    ///     .replacen('L', "M", 1);
+    /// let circle = Path::from_str(&(svg + "Z")).unwrap();
+    ///
+    /// assert!(circle.bbox_contains_point(Point(0.0, 0.0)));
+    /// assert!(circle.bbox_contains_point(Point(49.0, 49.0)));
+    /// assert!(!circle.bbox_contains_point(Point(100.0, 0.0)));
+    /// ```
+    pub fn bbox_contains_point(&self, p: point::Point) -> bool {
+        lyon::algorithms::aabb::bounding_box(self.inner.iter()).contains_inclusive(p.use_as())
+    }
+
+    /// Checks if a point lies inside the path, according to a fill rule.
+    ///
+    /// Curves are flattened to within `tolerance` for the test; `0.01` suits paths measured
+    /// in units like millimeters or pixels. Open subpaths are treated as if closed.
+    ///
+    /// The fill rule only matters where subpaths overlap. With [`FillRule::EvenOdd`], a
+    /// point is inside when a ray from it crosses the outline an odd number of times, so
+    /// any region covered twice is outside. With [`FillRule::NonZero`], the crossings are
+    /// counted with the direction of travel, so a region covered twice by subpaths running
+    /// the same way is inside, while a hole running the opposite way is still outside.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::{FillRule, Path, point::Point};
+    /// use std::str::FromStr;
+    ///
+    /// // Two overlapping squares running the same way.
+    /// let squares = Path::from_str("M0,0 L10,0 L10,10 L0,10 Z M5,5 L15,5 L15,15 L5,15 Z").unwrap();
+    /// let overlap = Point(7.0, 7.0);
+    /// assert!(squares.contains_point(overlap, FillRule::NonZero, 0.01));
+    /// assert!(!squares.contains_point(overlap, FillRule::EvenOdd, 0.01));
+    /// assert!(squares.contains_point(Point(2.0, 2.0), FillRule::EvenOdd, 0.01));
+    /// assert!(!squares.contains_point(Point(12.0, 2.0), FillRule::NonZero, 0.01));
+    ///
+    /// // A hole running the opposite way is outside with either rule.
+    /// let frame = Path::from_str("M0,0 L10,0 L10,10 L0,10 Z M3,3 L3,7 L7,7 L7,3 Z").unwrap();
+    /// assert!(!frame.contains_point(Point(5.0, 5.0), FillRule::NonZero, 0.01));
+    /// assert!(!frame.contains_point(Point(5.0, 5.0), FillRule::EvenOdd, 0.01));
+    /// ```
+    pub fn contains_point(&self, p: point::Point, fill_rule: FillRule, tolerance: f64) -> bool {
+        // The bounding box check rejects most points before the full hit test.
+        self.bbox_contains_point(p)
+            && lyon::algorithms::hit_test::hit_test_path(
+                &p.use_as(),
+                &self.inner,
+                fill_rule,
+                tolerance as f32,
+            )
+    }
+
+    /// Checks if this path is geometrically contained within another path, whose interior
+    /// is given by the fill rule.
+    ///
+    /// The curves of the other path are flattened to within a thousandth of its size, so
+    /// the test is as precise for a path measured in meters as for one in micrometers.
+    fn contained_by(&self, other_path: &Path, fill_rule: FillRule) -> bool {
         // A path cannot contain itself.
         !std::ptr::eq(self, other_path)
             // Both paths must be closed to have a well-defined interior.
             && self.is_closed()
             && other_path.is_closed()
             // Check if the first point of this path is inside the other path.
-            && self.inner.first_endpoint().map_or(false, |(pt, _)| {
-                lyon::algorithms::hit_test::hit_test_path(
-                    &pt,
-                    &other_path.inner,
-                    lyon::path::FillRule::EvenOdd,
-                    0.1,
-                )
+            && self.inner.first_endpoint().is_some_and(|(pt, _)| {
+                let bounds = lyon::algorithms::aabb::bounding_box(other_path.inner.iter());
+                let size = bounds.width().max(bounds.height()) as f64;
+                let tolerance = (size * 1e-3).max(f64::from(f32::EPSILON));
+                other_path.contains_point(pt.use_as(), fill_rule, tolerance)
             })
     }
 }
@@ -101,7 +648,20 @@ impl Path {
 ///
 /// # Errors
 ///
-/// Returns a `PathError` if the SVG path data is invalid.
+/// Returns a [`PathError::Parse`] if the SVG path data is invalid, locating the token that
+/// failed to parse.
+///
+/// # Example
+///
+/// ```
+/// use path_offset::error::PathError;
+/// use path_offset::path::Path;
+/// use std::str::FromStr;
+///
+/// let error = Path::from_str("M0,0 L10,0\nL10,#5 L0,10 Z").unwrap_err();
+/// assert!(matches!(&error, PathError::Parse { position: 15, near, .. } if near == "#5"));
+/// assert!(error.to_string().contains("at position 15 near \"#5\""));
+/// ```
 impl FromStr for Path {
     type Err = PathError;
 
@@ -110,11 +670,13 @@ impl FromStr for Path {
         let mut builder = lyon::path::Path::builder();
         let mut src = lyon::extra::parser::Source::new(s.chars());
 
-        parser.parse(
-            &lyon::extra::parser::ParserOptions::DEFAULT,
-            &mut src,
-            &mut builder,
-        )?;
+        parser
+            .parse(
+                &lyon::extra::parser::ParserOptions::DEFAULT,
+                &mut src,
+                &mut builder,
+            )
+            .map_err(|error| PathError::parse(s, error))?;
 
         let path = builder.build();
         Ok(Path::from(path))
@@ -122,6 +684,9 @@ impl FromStr for Path {
 }
 
 /// Formats the `Path` as an SVG path data string.
+///
+/// Coordinates are written with the full precision of `f32`. Use
+/// [`Path::to_svg_with_precision`] for rounded, more compact output.
 impl Display for Path {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let path_slice = self.inner.as_slice();
@@ -163,33 +728,186 @@ impl Display for Path {
     }
 }
 
-/// Strategy 1: Find the outermost shell by calculating signed area.
-/// This is a fast heuristic.
-fn find_shell_by_area(paths: &[Path]) -> Option<Path> {
-    paths
-        .iter()
-        // Only consider closed paths, as only they can define an inside and outside.
-        .filter(|p| p.is_closed())
-        .max_by(|a, b| {
-            let area_a = lyon::algorithms::area::approximate_signed_area(0.01, a.inner.iter());
-            let area_b = lyon::algorithms::area::approximate_signed_area(0.01, b.inner.iter());
-            // total_cmp can handle special f32 cases like NaN and infinity.
-            area_a.total_cmp(&area_b)
-        })
-        .cloned()
+/// Hashes the exact geometry of the `Path`.
+///
+/// Every event and every coordinate is hashed bit for bit, together with the per-vertex
+/// scalars, so paths that differ in any way, even by the smallest rounding, hash differently.
+/// This makes a path usable as a cache key for results computed from it.
+impl Hash for Path {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for event in self.inner.iter() {
+            let points: &[lyon::math::Point] = match &event {
+                Event::Begin { at } => &[*at],
+                Event::Line { to, .. } => &[*to],
+                Event::Quadratic { ctrl, to, .. } => &[*ctrl, *to],
+                Event::Cubic {
+                    ctrl1, ctrl2, to, ..
+                } => &[*ctrl1, *ctrl2, *to],
+                Event::End { close, .. } => {
+                    close.hash(state);
+                    continue;
+                }
+            };
+            points.len().hash(state);
+            for point in points {
+                point.x.to_bits().hash(state);
+                point.y.to_bits().hash(state);
+            }
+        }
+
+        if let Some(scalars) = &self.scalars {
+            for scalar in scalars {
+                scalar.to_bits().hash(state);
+            }
+        }
+    }
+}
+
+impl Path {
+    /// Checks whether two paths describe the same geometry, up to `tolerance`.
+    ///
+    /// Both paths must have the same number of subpaths, in the same order and with the same
+    /// closedness, made of the same kinds of segments with every endpoint and control point
+    /// within `tolerance` of its counterpart. A closed subpath may start at a different
+    /// vertex of the same loop, as long as it runs in the same direction: its implicit
+    /// closing segment is compared like any other line. Per-vertex scalars are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// let square = Path::from_str("M0,0 L10,0 L10,10 L0,10 Z").unwrap();
+    ///
+    /// let nudged = Path::from_str("M0,0.001 L10,0 L10,10 L0,10 Z").unwrap();
+    /// assert!(square.approx_eq(&nudged, 0.01));
+    /// assert!(!square.approx_eq(&nudged, 0.0001));
+    ///
+    /// // The same loop starting from another corner.
+    /// let rotated = Path::from_str("M10,10 L0,10 L0,0 L10,0 Z").unwrap();
+    /// assert!(square.approx_eq(&rotated, 0.01));
+    ///
+    /// // The same loop in the opposite direction.
+    /// assert!(!square.approx_eq(&square.reverse(), 0.01));
+    /// ```
+    pub fn approx_eq(&self, other: &Path, tolerance: f64) -> bool {
+        let subpaths_a: Vec<Path> = self.iter().collect();
+        let subpaths_b: Vec<Path> = other.iter().collect();
+
+        subpaths_a.len() == subpaths_b.len()
+            && subpaths_a.iter().zip(&subpaths_b).all(|(a, b)| {
+                let (start_a, segments_a) = a.segment_points();
+                let (start_b, segments_b) = b.segment_points();
+                let close = |p: &point::Point, q: &point::Point| p.distance_to(*q) <= tolerance;
+                let same_segment = |s: &Vec<point::Point>, t: &Vec<point::Point>| {
+                    s.len() == t.len() && s.iter().zip(t).all(|(p, q)| close(p, q))
+                };
+
+                if a.is_closed() != b.is_closed() || segments_a.len() != segments_b.len() {
+                    return false;
+                }
+                if !a.is_closed() {
+                    return close(&start_a, &start_b)
+                        && segments_a
+                            .iter()
+                            .zip(&segments_b)
+                            .all(|(s, t)| same_segment(s, t));
+                }
+
+                // A closed loop is its cycle of segments, whichever vertex it starts at.
+                let count = segments_a.len();
+                (0..count.max(1)).any(|shift| {
+                    (0..count)
+                        .all(|i| same_segment(&segments_a[i], &segments_b[(i + shift) % count]))
+                })
+            })
+    }
+
+    /// Returns the start point of a single subpath and the points of each of its segments.
+    ///
+    /// Each segment lists its control points followed by its end point. A closed subpath
+    /// includes its implicit closing line, unless it already ends at its start point.
+    fn segment_points(&self) -> (point::Point, Vec<Vec<point::Point>>) {
+        let mut start = point::Point(0.0, 0.0);
+        let mut segments = Vec::new();
+
+        for event in self.inner.iter() {
+            match event {
+                Event::Begin { at } => start = at.use_as(),
+                Event::Line { to, .. } => segments.push(vec![to.use_as()]),
+                Event::Quadratic { ctrl, to, .. } => {
+                    segments.push(vec![ctrl.use_as(), to.use_as()]);
+                }
+                Event::Cubic {
+                    ctrl1, ctrl2, to, ..
+                } => segments.push(vec![ctrl1.use_as(), ctrl2.use_as(), to.use_as()]),
+                Event::End { last, first, close } => {
+                    if close && last != first {
+                        segments.push(vec![first.use_as()]);
+                    }
+                }
+            }
+        }
+
+        (start, segments)
+    }
+
+    /// Checks whether two paths have exactly the same events, coordinates, and scalars.
+    pub(crate) fn identical_to(&self, other: &Path) -> bool {
+        self.inner.iter().eq(other.inner.iter()) && self.scalars == other.scalars
+    }
+}
+
+/// Strategy 1: Find the outermost shell by calculating the absolute area.
+/// This is a fast heuristic: a contour inside another one is usually smaller, so the
+/// largest contour is usually not contained by another, whichever way it winds. The
+/// exceptions are self-overlapping contours, whose loops can cancel out each other's
+/// area, so the largest contour is only accepted once it is checked to be uncontained.
+fn find_shell_by_area(paths: &[Path], fill_rule: FillRule) -> Option<usize> {
+    largest_by_area(
+        paths
+            .iter()
+            .enumerate()
+            // Only consider closed paths, as only they can define an inside and outside.
+            .filter(|(_, p)| p.is_closed()),
+    )
+    .filter(|&index| !is_contained(&paths[index], paths, fill_rule))
 }
 
 /// Strategy 2: Find the outermost shell by checking for geometric containment.
 /// This is a precise but computationally more expensive algorithm.
-fn find_shell_by_containment(paths: &[Path]) -> Option<Path> {
+///
+/// Disjoint shapes can leave several paths that no other path contains; the largest of
+/// them is the shell, as with the area heuristic.
+fn find_shell_by_containment(paths: &[Path], fill_rule: FillRule) -> Option<usize> {
+    largest_by_area(
+        paths
+            .iter()
+            .enumerate()
+            // Find the paths that are not contained by any other path.
+            .filter(|(_, this_path)| !is_contained(this_path, paths, fill_rule)),
+    )
+}
+
+/// Checks whether any of `paths` contains `this_path`.
+fn is_contained(this_path: &Path, paths: &[Path], fill_rule: FillRule) -> bool {
+    paths.iter().any(|other_path| {
+        // Use our previously defined helper methods.
+        this_path.bbox_intersects(other_path) && this_path.contained_by(other_path, fill_rule)
+    })
+}
+
+/// Returns the index of the path with the largest absolute area, the first one on ties.
+fn largest_by_area<'a>(paths: impl DoubleEndedIterator<Item = (usize, &'a Path)>) -> Option<usize> {
     paths
-        .iter()
-        .find(|this_path| {
-            // Find a path that is not contained by any other path.
-            !paths.iter().any(|other_path| {
-                // Use our previously defined helper methods.
-                this_path.intersect_with(other_path) && this_path.contained_by(other_path)
-            })
+        // `max_by` keeps the last of several equal elements.
+        .rev()
+        .max_by(|(_, a), (_, b)| {
+            let area_a = a.approximate_signed_area(0.01).abs();
+            let area_b = b.approximate_signed_area(0.01).abs();
+            // total_cmp can handle special f32 cases like NaN and infinity.
+            area_a.total_cmp(&area_b)
         })
-        .cloned()
+        .map(|(index, _)| index)
 }
@@ -9,9 +9,83 @@
 ///
 /// This struct acts as a common ground for converting between point types
 /// from different libraries (e.g., `lyon::math::Point`, `flo_curves::bezier::Coord2`).
-#[derive(Debug, Clone, Copy)]
+///
+/// Points double as 2D vectors: they can be added, subtracted, negated and scaled
+/// component-wise, which keeps control point math readable.
+///
+/// # Example
+///
+/// ```
+/// use path_offset::path::point::Point;
+///
+/// let (from, ctrl, to) = (Point(0.0, 0.0), Point(3.0, 6.0), Point(6.0, 0.0));
+///
+/// // The control points of the cubic curve equivalent to a quadratic one.
+/// let ctrl1 = from + (ctrl - from) * (2.0 / 3.0);
+/// let ctrl2 = to + (ctrl - to) * (2.0 / 3.0);
+/// assert_eq!(ctrl1, Point(2.0, 4.0));
+/// assert_eq!(ctrl2, Point(4.0, 4.0));
+///
+/// assert_eq!(from.lerp(to, 0.5), Point(3.0, 0.0));
+/// assert_eq!(from.distance_to(Point(3.0, 4.0)), 5.0);
+/// assert_eq!((ctrl - from).dot(to - from), 18.0);
+/// assert_eq!(-ctrl, Point(-3.0, -6.0));
+/// ```
+///
+/// With the `serde` feature, a point serializes as a tuple of its two coordinates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Point(pub f64, pub f64);
 
+impl Point {
+    /// Returns the Euclidean distance between two points.
+    pub fn distance_to(self, other: Point) -> f64 {
+        (other.0 - self.0).hypot(other.1 - self.1)
+    }
+
+    /// Returns the dot product of two points taken as vectors.
+    pub fn dot(self, other: Point) -> f64 {
+        self.0 * other.0 + self.1 * other.1
+    }
+
+    /// Interpolates linearly between two points: `t = 0` gives `self`, `t = 1` gives `other`.
+    pub fn lerp(self, other: Point, t: f64) -> Point {
+        self + (other - self) * t
+    }
+}
+
+impl std::ops::Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point(self.0 + other.0, self.1 + other.1)
+    }
+}
+
+impl std::ops::Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point(self.0 - other.0, self.1 - other.1)
+    }
+}
+
+impl std::ops::Mul<f64> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: f64) -> Point {
+        Point(self.0 * factor, self.1 * factor)
+    }
+}
+
+impl std::ops::Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point(-self.0, -self.1)
+    }
+}
+
 /// A trait for generically converting between different point types.
 ///
 /// Any type that implements `Copy` and has `From` implementations to and from
@@ -69,6 +143,48 @@ impl From<Point> for lyon::math::Point {
     }
 }
 
+/// Converts an `f64` `euclid` point, such as `lyon::geom::Point<f64>`, to the canonical
+/// `Point`, whatever its unit.
+impl<U> From<lyon::geom::euclid::Point2D<f64, U>> for Point {
+    fn from(value: lyon::geom::euclid::Point2D<f64, U>) -> Self {
+        Self(value.x, value.y)
+    }
+}
+
+/// Converts the canonical `Point` to an `f64` `euclid` point of any unit.
+impl<U> From<Point> for lyon::geom::euclid::Point2D<f64, U> {
+    fn from(point: Point) -> Self {
+        lyon::geom::euclid::point2(point.0, point.1)
+    }
+}
+
+/// Converts an `(x, y)` tuple to the canonical `Point`.
+///
+/// # Example
+///
+/// ```
+/// use path_offset::path::point::{Point, PointConvert};
+///
+/// let point: lyon::math::Point = (1.5, -2.0).use_as();
+/// assert_eq!(point, lyon::math::point(1.5, -2.0));
+/// assert_eq!(point.use_as::<(f64, f64)>(), (1.5, -2.0));
+///
+/// let precise: lyon::geom::Point<f64> = Point(0.1, 0.2).use_as();
+/// assert_eq!(precise.use_as::<(f64, f64)>(), (0.1, 0.2));
+/// ```
+impl From<(f64, f64)> for Point {
+    fn from((x, y): (f64, f64)) -> Self {
+        Self(x, y)
+    }
+}
+
+/// Converts the canonical `Point` to an `(x, y)` tuple.
+impl From<Point> for (f64, f64) {
+    fn from(point: Point) -> Self {
+        (point.0, point.1)
+    }
+}
+
 /// Converts a `flo_curves::bezier::Coord2` to the canonical `Point`.
 impl From<flo_curves::bezier::Coord2> for Point {
     fn from(value: flo_curves::bezier::Coord2) -> Self {
@@ -82,3 +198,61 @@ impl From<Point> for flo_curves::bezier::Coord2 {
         flo_curves::bezier::Coord2(point.0, point.1)
     }
 }
+
+/// Converts a `cavalier_contours::core::math::Vector2` to the canonical `Point`.
+impl From<cavalier_contours::core::math::Vector2<f64>> for Point {
+    fn from(value: cavalier_contours::core::math::Vector2<f64>) -> Self {
+        Self(value.x, value.y)
+    }
+}
+
+/// Converts the canonical `Point` to a `cavalier_contours::core::math::Vector2`.
+impl From<Point> for cavalier_contours::core::math::Vector2<f64> {
+    fn from(point: Point) -> Self {
+        cavalier_contours::core::math::Vector2::new(point.0, point.1)
+    }
+}
+
+/// Converts a `kurbo::Point` to the canonical `Point`.
+#[cfg(feature = "kurbo")]
+impl From<kurbo::Point> for Point {
+    fn from(value: kurbo::Point) -> Self {
+        Self(value.x, value.y)
+    }
+}
+
+/// Converts the canonical `Point` to a `kurbo::Point`.
+#[cfg(feature = "kurbo")]
+impl From<Point> for kurbo::Point {
+    fn from(point: Point) -> Self {
+        kurbo::Point::new(point.0, point.1)
+    }
+}
+
+/// Converts a `nalgebra::Point2<f64>` to the canonical `Point`.
+///
+/// # Example
+///
+/// ```
+/// use nalgebra::{Isometry2, Point2, Vector2};
+/// use path_offset::path::point::{Point, PointConvert};
+///
+/// // Move a point through a nalgebra transform and back.
+/// let transform = Isometry2::new(Vector2::new(1.0, 2.0), std::f64::consts::FRAC_PI_2);
+/// let moved: Point = (transform * Point(3.0, 0.0).use_as::<Point2<f64>>()).use_as();
+/// assert!(moved.distance_to(Point(1.0, 5.0)) < 1e-12);
+/// ```
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Point2<f64>> for Point {
+    fn from(value: nalgebra::Point2<f64>) -> Self {
+        Self(value.x, value.y)
+    }
+}
+
+/// Converts the canonical `Point` to a `nalgebra::Point2<f64>`.
+#[cfg(feature = "nalgebra")]
+impl From<Point> for nalgebra::Point2<f64> {
+    fn from(point: Point) -> Self {
+        nalgebra::Point2::new(point.0, point.1)
+    }
+}
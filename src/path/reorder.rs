@@ -0,0 +1,123 @@
+//! Provides ways of re-sequencing the segments of a path without changing its shape.
+
+use lyon::path::Event;
+
+use super::point::{Point, PointConvert};
+
+impl super::Path {
+    /// Rotates a closed subpath so that it starts at the vertex nearest `near`.
+    ///
+    /// The segments of the subpath are re-sequenced, not altered, so the traced outline and
+    /// its winding stay the same. This lets toolpaths start close to where the tool already
+    /// is, minimizing travel moves. An implicit closing segment becomes an explicit line if
+    /// it ends up in the middle of the sequence. Per-vertex scalars follow their vertices.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the subpath to rotate, as yielded by [`Path::iter`](super::Path::iter).
+    /// * `near` - The point the new start vertex should be nearest to.
+    /// * `tolerance` - Vertices whose distances to `near` differ by at most this much are
+    ///   considered equally near, and the first of them in the current order is chosen.
+    ///
+    /// # Returns
+    ///
+    /// The re-sequenced path. It is an unchanged copy if `index` is out of range or the
+    /// subpath is open, since an open subpath cannot start anywhere but at its first vertex.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::{Path, point::Point};
+    /// use std::str::FromStr;
+    ///
+    /// let path = Path::from_str("M20,0 L30,0 M0,0 L10,0 L10,10 L0,10 Z").unwrap();
+    /// let rotated = path.rotate_start_to(1, Point(9.0, 8.0), 0.01);
+    ///
+    /// assert_eq!(rotated.to_string(), "M20,0L30,0M10,10L0,10L0,0L10,0Z");
+    /// ```
+    pub fn rotate_start_to(&self, index: usize, near: Point, tolerance: f32) -> super::Path {
+        let subpaths: Vec<super::Path> = self.iter().collect();
+        match subpaths.get(index) {
+            Some(subpath) if subpath.is_closed() => subpaths
+                .iter()
+                .enumerate()
+                .map(|(i, subpath)| {
+                    if i == index {
+                        subpath.rotated_start(near, tolerance)
+                    } else {
+                        subpath.clone()
+                    }
+                })
+                .collect(),
+            _ => self.clone(),
+        }
+    }
+
+    /// Rotates this single closed subpath so that it starts at the vertex nearest `near`.
+    fn rotated_start(&self, near: Point, tolerance: f32) -> super::Path {
+        let mut segments = Vec::new();
+        for event in self.inner.iter() {
+            match event {
+                Event::Begin { .. } => {}
+                Event::End { last, first, .. } => {
+                    if last != first {
+                        segments.push(Event::Line {
+                            from: last,
+                            to: first,
+                        });
+                    }
+                }
+                segment => segments.push(segment),
+            }
+        }
+        if segments.is_empty() {
+            return self.clone();
+        }
+
+        // Vertex `k` is where segment `k` starts.
+        let near: lyon::math::Point = near.use_as();
+        let distances: Vec<f32> = segments
+            .iter()
+            .map(|segment| (segment.from() - near).length())
+            .collect();
+        let nearest = distances.iter().copied().fold(f32::INFINITY, f32::min);
+        let start = distances
+            .iter()
+            .position(|distance| *distance - nearest <= tolerance)
+            .unwrap_or(0);
+
+        let count = segments.len();
+        let vertex_scalars = self.scalars.as_deref();
+        let mut scalars = vertex_scalars.map(|scalars| vec![scalars[start]]);
+        let mut builder = lyon::path::Path::builder();
+        builder.begin(segments[start].from());
+
+        for (step, k) in (start..count).chain(0..start).enumerate() {
+            match segments[k] {
+                // The final line back to the start is implied by closing the subpath.
+                Event::Line { .. } if step == count - 1 => break,
+                Event::Line { to, .. } => {
+                    builder.line_to(to);
+                }
+                Event::Quadratic { ctrl, to, .. } => {
+                    builder.quadratic_bezier_to(ctrl, to);
+                }
+                Event::Cubic {
+                    ctrl1, ctrl2, to, ..
+                } => {
+                    builder.cubic_bezier_to(ctrl1, ctrl2, to);
+                }
+                _ => {}
+            }
+            if let (Some(scalars), Some(vertex_scalars)) = (&mut scalars, vertex_scalars) {
+                scalars.push(vertex_scalars[(k + 1) % count]);
+            }
+        }
+        builder.end(true);
+
+        super::Path {
+            inner: builder.build(),
+            scalars,
+        }
+    }
+}
@@ -0,0 +1,114 @@
+//! Summarizes the geometry of a path in a single report.
+//!
+//! [`Path::report`](super::Path::report) gathers the counts and measurements that are most
+//! often logged when debugging an offset, and [`PathReport`] prints them on one line.
+
+use std::fmt::Display;
+
+use lyon::path::Event;
+
+use super::point::Point;
+
+/// A summary of the geometry of a path, as returned by [`Path::report`](super::Path::report).
+#[derive(Debug, Clone, PartialEq)]
+pub struct PathReport {
+    /// The number of subpaths.
+    pub subpaths: usize,
+    /// The number of explicit segments (lines and curves). Implicit closing segments are
+    /// not counted.
+    pub segments: usize,
+    /// The number of closed subpaths.
+    pub closed: usize,
+    /// The number of open subpaths.
+    pub open: usize,
+    /// The minimum and maximum corners of the flattened path, or `None` if it has no points.
+    pub bounds: Option<(Point, Point)>,
+    /// The total length of the flattened path, including closing segments.
+    pub length: f64,
+    /// Whether any subpath intersects itself.
+    pub self_intersecting: bool,
+}
+
+impl Display for PathReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} subpaths ({} closed, {} open), {} segments, length {}",
+            self.subpaths, self.closed, self.open, self.segments, self.length
+        )?;
+        if let Some((min, max)) = self.bounds {
+            write!(f, ", bounds ({},{})-({},{})", min.0, min.1, max.0, max.1)?;
+        }
+        if self.self_intersecting {
+            write!(f, ", self-intersecting")?;
+        }
+        Ok(())
+    }
+}
+
+impl super::Path {
+    /// Summarizes the geometry of the path for logging and debugging.
+    ///
+    /// # Arguments
+    ///
+    /// * `tolerance` - The flattening tolerance used for the bounds, the length, and the
+    ///   self-intersection test.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::{Path, point::Point};
+    /// use std::str::FromStr;
+    ///
+    /// let path = Path::from_str("M0,0 L10,0 L10,10 L0,10 Z M20,0 L30,0").unwrap();
+    /// let report = path.report(0.01);
+    ///
+    /// assert_eq!(report.subpaths, 2);
+    /// assert_eq!(report.segments, 4);
+    /// assert_eq!((report.closed, report.open), (1, 1));
+    /// assert_eq!(report.bounds, Some((Point(0.0, 0.0), Point(30.0, 10.0))));
+    /// assert_eq!(report.length, 50.0);
+    /// assert!(!report.self_intersecting);
+    /// assert_eq!(
+    ///     report.to_string(),
+    ///     "2 subpaths (1 closed, 1 open), 4 segments, length 50, bounds (0,0)-(30,10)",
+    /// );
+    /// ```
+    pub fn report(&self, tolerance: f32) -> PathReport {
+        let subpaths: Vec<super::Path> = self.iter().collect();
+        let closed = subpaths
+            .iter()
+            .filter(|subpath| subpath.is_closed())
+            .count();
+
+        let segments = self
+            .inner
+            .iter()
+            .filter(|event| !matches!(event, Event::Begin { .. } | Event::End { .. }))
+            .count();
+
+        let bounds = self
+            .polylines(tolerance)
+            .iter()
+            .flat_map(|(points, _)| points.iter().copied())
+            .map(|p| (Point(p.x as f64, p.y as f64), Point(p.x as f64, p.y as f64)))
+            .reduce(|(min, max), (p, _)| {
+                (
+                    Point(min.0.min(p.0), min.1.min(p.1)),
+                    Point(max.0.max(p.0), max.1.max(p.1)),
+                )
+            });
+
+        let length = self.length(tolerance as f64);
+
+        PathReport {
+            subpaths: subpaths.len(),
+            segments,
+            closed,
+            open: subpaths.len() - closed,
+            bounds,
+            length,
+            self_intersecting: !self.self_intersections(tolerance as f64).is_empty(),
+        }
+    }
+}
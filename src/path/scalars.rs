@@ -0,0 +1,175 @@
+//! Provides an optional per-vertex scalar channel for paths.
+//!
+//! CNC and plotting workflows often attach a value such as a Z depth or a feed rate to
+//! each vertex. A `Path` can carry one `f64` per vertex alongside its geometry. The
+//! channel is preserved by transformations, subpath iteration, and concatenation, and is
+//! interpolated onto new vertices by [`Path::simplify`](super::Path::simplify). Offsetting
+//! gives each vertex of the offset the scalar of the nearest point of the source path.
+//! Boolean operations compute geometry that belongs to no single input, and drop it.
+
+use lyon::geom::LineSegment;
+
+use crate::error::{PathError, Result};
+
+impl super::Path {
+    /// Attaches one scalar value per vertex to the path.
+    ///
+    /// Vertices are counted in event order across all subpaths: the start point of each
+    /// subpath followed by the end point of each of its segments. Control points are not
+    /// vertices, and closing a subpath does not add one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PathError::ScalarCount`] if the number of scalars differs from the number
+    /// of vertices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::error::PathError;
+    /// use path_offset::offset::{JoinStyle, Offset, cavalier_contours::CavalierContours};
+    /// use path_offset::path::{Path, point::Point, transform::Transform};
+    /// use std::str::FromStr;
+    ///
+    /// let path = Path::from_str("M0,0 L10,0 L10,10 M20,0 L30,0")
+    ///     .unwrap()
+    ///     .with_scalars(vec![0.0, -1.0, -2.0, -3.0, -4.0])
+    ///     .unwrap();
+    ///
+    /// // Transformations keep the channel as it is.
+    /// let mirrored = path.mirror(Point(0.0, 0.0), Point(0.0, 1.0));
+    /// assert_eq!(mirrored.scalars(), Some(&[0.0, -1.0, -2.0, -3.0, -4.0][..]));
+    /// let moved = path.transform(&Transform::translate(5.0, -5.0));
+    /// assert_eq!(moved.scalars(), path.scalars());
+    ///
+    /// // Each subpath carries its own slice of the channel.
+    /// let second = path.iter().nth(1).unwrap();
+    /// assert_eq!(second.scalars(), Some(&[-3.0, -4.0][..]));
+    ///
+    /// // Offsetting gives each vertex the scalar of the nearest point of the source.
+    /// let square = Path::from_str("M0,0 L10,0 L10,10 L0,10 Z")
+    ///     .unwrap()
+    ///     .with_scalars(vec![0.0, -1.0, -2.0, -3.0])
+    ///     .unwrap();
+    /// let grown = CavalierContours::new(1.0)
+    ///     .with_join_style(JoinStyle::Miter { limit: 4.0 })
+    ///     .offset_path(&square)
+    ///     .unwrap();
+    /// assert_eq!(grown.to_string(), "M-1,-1L11,-1L11,11L-1,11Z");
+    /// assert_eq!(grown.scalars(), Some(&[0.0, -1.0, -2.0, -3.0][..]));
+    ///
+    /// assert!(matches!(
+    ///     Path::from_str("M0,0 L10,0").unwrap().with_scalars(vec![0.0]),
+    ///     Err(PathError::ScalarCount { expected: 2, found: 1 })
+    /// ));
+    /// ```
+    pub fn with_scalars(mut self, scalars: Vec<f64>) -> Result<super::Path> {
+        let expected = self.vertex_count();
+        if scalars.len() != expected {
+            return Err(PathError::ScalarCount {
+                expected,
+                found: scalars.len(),
+            });
+        }
+        self.scalars = Some(scalars);
+        Ok(self)
+    }
+
+    /// Returns the per-vertex scalars of the path, if it has any.
+    pub fn scalars(&self) -> Option<&[f64]> {
+        self.scalars.as_deref()
+    }
+
+    /// Returns the number of vertices of the path, as counted by [`Path::with_scalars`](super::Path::with_scalars).
+    pub(crate) fn vertex_count(&self) -> usize {
+        self.inner
+            .iter()
+            .filter(|event| !matches!(event, lyon::path::Event::End { .. }))
+            .count()
+    }
+
+    /// Gives each vertex of this path the scalar of the nearest point of `source`.
+    ///
+    /// This carries the channel over to geometry computed from `source`, such as its offset.
+    /// The scalars of `source` are interpolated along its segments, with curves flattened,
+    /// so a vertex beside the middle of a segment takes the average of its ends. The path is
+    /// returned unchanged if `source` carries no scalars.
+    pub(crate) fn with_scalars_from(mut self, source: &super::Path) -> super::Path {
+        if source.scalars.is_none() {
+            return self;
+        }
+
+        // The flattened segments of `source`, with the scalars at both of their ends.
+        let mut segments = Vec::new();
+        for (points, params, closed) in source.flattened_subpaths(0.01) {
+            let scalars: Vec<f64> = params
+                .iter()
+                .filter_map(|&param| source.scalar_at(param))
+                .collect();
+            let count = points.len();
+            let ends = if closed && count > 2 {
+                count
+            } else {
+                count - 1
+            };
+            segments.extend((0..ends.max(1)).map(|index| {
+                let next = (index + 1) % count;
+                (
+                    LineSegment {
+                        from: points[index].cast::<f64>(),
+                        to: points[next].cast::<f64>(),
+                    },
+                    scalars[index],
+                    scalars[next],
+                )
+            }));
+        }
+
+        let scalars = self
+            .inner
+            .iter()
+            .filter_map(|event| match event {
+                lyon::path::Event::Begin { at } => Some(at),
+                lyon::path::Event::Line { to, .. }
+                | lyon::path::Event::Quadratic { to, .. }
+                | lyon::path::Event::Cubic { to, .. } => Some(to),
+                lyon::path::Event::End { .. } => None,
+            })
+            .map(|vertex| {
+                let vertex = vertex.cast::<f64>();
+                segments
+                    .iter()
+                    .map(|(segment, start, end)| {
+                        let along = segment.to_vector();
+                        let length = along.square_length();
+                        let t = if length > 0.0 {
+                            ((vertex - segment.from).dot(along) / length).clamp(0.0, 1.0)
+                        } else {
+                            0.0
+                        };
+                        let distance = (segment.sample(t) - vertex).square_length();
+                        (distance, start + (end - start) * t)
+                    })
+                    .min_by(|(a, _), (b, _)| a.total_cmp(b))
+                    .map_or(0.0, |(_, scalar)| scalar)
+            })
+            .collect();
+        self.scalars = Some(scalars);
+        self
+    }
+
+    /// Interpolates the scalar channel at a fractional vertex index.
+    ///
+    /// Returns `None` if the path carries no scalars.
+    pub(crate) fn scalar_at(&self, vertex: f64) -> Option<f64> {
+        let scalars = self.scalars.as_deref()?;
+        let last = scalars.len().checked_sub(1)?;
+        let index = (vertex.floor().max(0.0) as usize).min(last);
+        let t = vertex - index as f64;
+
+        match scalars.get(index + 1) {
+            Some(next) if t > 0.0 => Some(scalars[index] + (next - scalars[index]) * t),
+            _ => Some(scalars[index]),
+        }
+    }
+}
@@ -0,0 +1,73 @@
+//! Implements `serde` serialization for `Path`, behind the `serde` feature.
+
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de, ser::SerializeStruct};
+
+use super::Path;
+
+/// Serializes the path as its SVG path data string, as written by `Display`.
+///
+/// Coordinates are written with the full precision of `f32`, so deserializing the string
+/// gives back the same geometry. Per-vertex scalars are not part of SVG path data, so a
+/// path that carries them is written as a struct instead, with the path data under `data`
+/// and the scalars under `scalars`.
+///
+/// # Example
+///
+/// ```
+/// use path_offset::path::{Path, point::Point};
+/// use std::str::FromStr;
+///
+/// let path = Path::from_str("M0,0 L10.5,0 Q10,10 0,10 Z M20,0 C20,5 25,10 30,10").unwrap();
+///
+/// let json = serde_json::to_string(&path).unwrap();
+/// assert_eq!(json, "\"M0,0L10.5,0Q10,10 0,10ZM20,0C20,5 25,10 30,10\"");
+/// let round_trip: Path = serde_json::from_str(&json).unwrap();
+/// assert!(round_trip.approx_eq(&path, 1e-6));
+///
+/// assert!(serde_json::from_str::<Path>("\"M0,0 L10,x\"").is_err());
+///
+/// // Scalars are written alongside the path data.
+/// let ramp = Path::from_str("M0,0 L10,0").unwrap().with_scalars(vec![0.0, -2.5]).unwrap();
+/// let json = serde_json::to_string(&ramp).unwrap();
+/// assert_eq!(json, r#"{"data":"M0,0L10,0","scalars":[0.0,-2.5]}"#);
+/// let round_trip: Path = serde_json::from_str(&json).unwrap();
+/// assert_eq!(round_trip.scalars(), Some(&[0.0, -2.5][..]));
+///
+/// // Points serialize as coordinate pairs.
+/// assert_eq!(serde_json::to_string(&Point(1.5, -2.0)).unwrap(), "[1.5,-2.0]");
+/// ```
+impl Serialize for Path {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let Some(scalars) = &self.scalars else {
+            return serializer.collect_str(self);
+        };
+
+        let mut state = serializer.serialize_struct("Path", 2)?;
+        state.serialize_field("data", &self.to_string())?;
+        state.serialize_field("scalars", scalars)?;
+        state.end()
+    }
+}
+
+/// The serialized forms of a path: bare path data, or path data with scalars.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Repr {
+    Data(String),
+    WithScalars { data: String, scalars: Vec<f64> },
+}
+
+/// Deserializes a path from SVG path data, as parsed by `from_str`, along with its
+/// per-vertex scalars if they were written.
+impl<'de> Deserialize<'de> for Path {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match Repr::deserialize(deserializer)? {
+            Repr::Data(data) => Path::from_str(&data).map_err(de::Error::custom),
+            Repr::WithScalars { data, scalars } => Path::from_str(&data)
+                .and_then(|path| path.with_scalars(scalars))
+                .map_err(de::Error::custom),
+        }
+    }
+}
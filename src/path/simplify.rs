@@ -0,0 +1,312 @@
+//! Provides polyline flattening and simplification for paths.
+//!
+//! [`Path::flatten`](super::Path::flatten) replaces curves with line segments for
+//! consumers that cannot handle them. Flattened, imported, and offset paths often carry far
+//! more vertices than their shape needs; [`Path::simplify`](super::Path::simplify) reduces
+//! each subpath with the Douglas–Peucker algorithm. Going the other way,
+//! [`Path::smooth_catmull_rom`](super::Path::smooth_catmull_rom) turns coarse polylines
+//! into smooth curves.
+
+use lyon::{geom::LineSegment, math::Point};
+
+use super::point::{Point as CanonicalPoint, PointConvert};
+
+impl super::Path {
+    /// Flattens the path into polylines, replacing every curve with line segments.
+    ///
+    /// The result contains only `Begin`, `Line`, and `End` events, which suits consumers
+    /// such as plotters and G-code generators. Each subpath keeps its closedness, and
+    /// per-vertex scalars are interpolated onto the new vertices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tolerance` - The maximum distance between a curve and the line segments
+    ///   replacing it. Smaller tolerances produce more segments.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::{Path, event::PathEvent};
+    /// use std::str::FromStr;
+    ///
+    /// // A half circle of radius 10 made of two cubic curves.
+    /// let half_circle =
+    ///     Path::from_str("M-10,0 C-10,-5.523 -5.523,-10 0,-10 C5.523,-10 10,-5.523 10,0 Z").unwrap();
+    ///
+    /// let coarse = half_circle.flatten(0.5);
+    /// let fine = half_circle.flatten(0.01);
+    /// assert!(fine.events().all(|event| matches!(
+    ///     event,
+    ///     PathEvent::Begin { .. } | PathEvent::Line { .. } | PathEvent::End { .. }
+    /// )));
+    /// assert!(fine.is_closed());
+    /// assert!(fine.events().count() > coarse.events().count());
+    /// ```
+    pub fn flatten(&self, tolerance: f64) -> super::Path {
+        let mut builder = lyon::path::Path::builder();
+        let mut scalars = self.scalars.as_ref().map(|_| Vec::new());
+
+        for (points, params, closed) in self.flattened_subpaths(tolerance as f32) {
+            let Some((first, rest)) = points.split_first() else {
+                continue;
+            };
+            builder.begin(*first);
+            for point in rest {
+                builder.line_to(*point);
+            }
+            builder.end(closed);
+
+            if let Some(scalars) = &mut scalars {
+                scalars.extend(params.iter().filter_map(|param| self.scalar_at(*param)));
+            }
+        }
+
+        super::Path {
+            inner: builder.build(),
+            scalars,
+        }
+    }
+
+    /// Simplifies the path by removing vertices that contribute little to its shape.
+    ///
+    /// Curves are first flattened into line segments, then each subpath's polyline is
+    /// reduced with the Douglas–Peucker algorithm: a vertex is only kept if it lies further
+    /// than `tolerance` from the chord between its retained neighbours. Runs of nearly
+    /// collinear segments therefore collapse into a single segment. The result contains
+    /// only line segments, and each subpath keeps its closedness. Per-vertex scalars are
+    /// interpolated onto the vertices of the simplified path.
+    ///
+    /// # Arguments
+    ///
+    /// * `tolerance` - The maximum deviation between the original and the simplified path.
+    ///   It is also used as the flattening tolerance for curves.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::{Path, event::PathEvent};
+    /// use std::str::FromStr;
+    ///
+    /// // A 100-point line that wobbles by at most 0.001 around the x axis.
+    /// let svg: String = (0..100)
+    ///     .map(|i| format!("L{},{} ", i, if i % 2 == 0 { 0.0 } else { 0.001 }))
+    ///     .collect();
+    /// let line = Path::from_str(&svg.replacen('L', "M", 1)).unwrap();
+    /// assert_eq!(line.events().count(), 101);
+    ///
+    /// let simplified = line.simplify(0.01);
+    /// assert_eq!(simplified.to_string(), "M0,0L99,0.001");
+    /// assert_eq!(simplified.events().filter(|event| !matches!(event, PathEvent::End { .. })).count(), 2);
+    ///
+    /// // Simplification keeps the scalars of the surviving vertices.
+    /// let line = Path::from_str("M0,0 L5,0 L10,0")
+    ///     .unwrap()
+    ///     .with_scalars(vec![0.0, 0.5, 1.0])
+    ///     .unwrap();
+    /// assert_eq!(line.simplify(0.1).scalars(), Some(&[0.0, 1.0][..]));
+    /// ```
+    pub fn simplify(&self, tolerance: f64) -> super::Path {
+        let tolerance = tolerance as f32;
+        let mut builder = lyon::path::Path::builder();
+        let mut scalars = self.scalars.as_ref().map(|_| Vec::new());
+
+        for (points, params, closed) in self.flattened_subpaths(tolerance) {
+            let kept = if closed {
+                simplify_ring(&points, tolerance)
+            } else {
+                douglas_peucker(&points, tolerance)
+            };
+
+            let Some((first, rest)) = kept.split_first() else {
+                continue;
+            };
+            builder.begin(points[*first]);
+            for index in rest {
+                builder.line_to(points[*index]);
+            }
+            builder.end(closed);
+
+            // Carry the scalar channel over, interpolated onto any new vertices.
+            if let Some(scalars) = &mut scalars {
+                scalars.extend(
+                    kept.iter()
+                        .filter_map(|index| self.scalar_at(params[*index])),
+                );
+            }
+        }
+
+        super::Path {
+            inner: builder.build(),
+            scalars,
+        }
+    }
+
+    /// Smooths each subpath into cubic Bézier curves passing through its anchor points.
+    ///
+    /// This is a cardinal spline: the tangent at each anchor is parallel to the chord
+    /// between its neighbours, scaled by `1 - tension`. The anchors are those of
+    /// [`Path::points`](super::Path::points), so existing control points are ignored and
+    /// every segment, including the closing segment of a closed subpath, becomes a cubic
+    /// curve. A closed subpath wraps around, taking its last anchor as the neighbour before
+    /// its first, so it stays smooth where it closes. An open subpath is clamped: its end
+    /// anchors act as their own outer neighbours. Per-vertex scalars are kept on the
+    /// anchors.
+    ///
+    /// # Arguments
+    ///
+    /// * `tension` - `0.0` gives a Catmull–Rom spline; values towards `1.0` tighten the
+    ///   curves towards the original polyline, which `1.0` reproduces exactly.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::{Path, event::PathEvent, point::Point};
+    /// use std::str::FromStr;
+    ///
+    /// let zigzag = Path::from_str("M0,0 L10,10 L20,0 L30,10").unwrap();
+    /// let smooth = zigzag.smooth_catmull_rom(0.0);
+    ///
+    /// // The curves pass through every original point.
+    /// assert!(smooth.events().all(|event| matches!(
+    ///     event,
+    ///     PathEvent::Begin { .. } | PathEvent::Cubic { .. } | PathEvent::End { .. }
+    /// )));
+    /// assert_eq!(smooth.points().collect::<Vec<_>>(), zigzag.points().collect::<Vec<_>>());
+    ///
+    /// // At (10, 10), the tangent is parallel to the chord from (0, 0) to (20, 0).
+    /// let Some(PathEvent::Cubic { ctrl2, to, .. }) = smooth.events().nth(1) else { panic!() };
+    /// assert_eq!(to, Point(10.0, 10.0));
+    /// assert!(ctrl2.distance_to(Point(10.0 - 20.0 / 6.0, 10.0)) < 1e-5);
+    ///
+    /// // A closed square becomes a closed, rounded loop.
+    /// let square = Path::from_str("M0,0 L10,0 L10,10 L0,10 Z").unwrap();
+    /// let rounded = square.smooth_catmull_rom(0.0);
+    /// assert!(rounded.is_closed());
+    /// assert_eq!(rounded.events().filter(|e| matches!(e, PathEvent::Cubic { .. })).count(), 4);
+    /// assert!(rounded.signed_area(0.01) > 100.0);
+    /// ```
+    pub fn smooth_catmull_rom(&self, tension: f64) -> super::Path {
+        let mut builder = lyon::path::Path::builder();
+        let mut scalars = self.scalars.as_ref().map(|_| Vec::new());
+        let scale = (1.0 - tension) / 2.0;
+
+        for subpath in self.iter() {
+            let closed = subpath.is_closed();
+            let mut points: Vec<CanonicalPoint> = subpath.points().collect();
+            let mut subpath_scalars = subpath.scalars.unwrap_or_default();
+            // A closed subpath returning to its start explicitly gets its closing
+            // segment from the wrap-around instead.
+            if closed && points.len() > 1 && points.first() == points.last() {
+                points.pop();
+                subpath_scalars.pop();
+            }
+
+            let count = points.len();
+            let neighbour = |index: isize| {
+                if closed {
+                    points[index.rem_euclid(count as isize) as usize]
+                } else {
+                    points[index.clamp(0, count as isize - 1) as usize]
+                }
+            };
+            let tangent = |index: usize| {
+                (neighbour(index as isize + 1) - neighbour(index as isize - 1)) * scale
+            };
+
+            builder.begin(points[0].use_as());
+            let segments = if closed { count } else { count - 1 };
+            for (index, &from) in points.iter().enumerate().take(segments) {
+                let to = neighbour(index as isize + 1);
+                let to_index = (index + 1) % count;
+                builder.cubic_bezier_to(
+                    (from + tangent(index) * (1.0 / 3.0)).use_as(),
+                    (to - tangent(to_index) * (1.0 / 3.0)).use_as(),
+                    to.use_as(),
+                );
+            }
+            builder.end(closed);
+
+            if let Some(scalars) = &mut scalars {
+                // The closing curve ends on an explicit copy of the first anchor.
+                let closing = closed.then(|| subpath_scalars.first().copied()).flatten();
+                scalars.extend(subpath_scalars.iter().copied().chain(closing));
+            }
+        }
+
+        super::Path {
+            inner: builder.build(),
+            scalars,
+        }
+    }
+}
+
+/// Simplifies a closed ring of points, returning the indices of the points to keep.
+///
+/// The ring is split at the vertex farthest from its first vertex so that each half has a
+/// non-degenerate chord, and both halves are simplified independently.
+fn simplify_ring(points: &[Point], tolerance: f32) -> Vec<usize> {
+    if points.len() < 4 {
+        return (0..points.len()).collect();
+    }
+
+    let first = points[0];
+    let (split, _) = points
+        .iter()
+        .enumerate()
+        .map(|(i, p)| (i, (*p - first).square_length()))
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+        .unwrap_or((0, 0.0));
+
+    let mut closed_points = points.to_vec();
+    closed_points.push(first);
+
+    let mut ring = douglas_peucker(&closed_points[..=split], tolerance);
+    ring.pop();
+    ring.extend(
+        douglas_peucker(&closed_points[split..], tolerance)
+            .into_iter()
+            .map(|index| index + split),
+    );
+    // The last index of the second half refers to the duplicated first point.
+    ring.pop();
+    ring
+}
+
+/// Simplifies an open polyline using the Douglas–Peucker algorithm.
+///
+/// Returns the indices of the points to keep, in order.
+fn douglas_peucker(points: &[Point], tolerance: f32) -> Vec<usize> {
+    if points.len() < 3 {
+        return (0..points.len()).collect();
+    }
+
+    let last = points.len() - 1;
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[last] = true;
+
+    // Use an explicit stack instead of recursion to stay safe on very long polylines.
+    let mut stack = vec![(0, last)];
+    while let Some((start, end)) = stack.pop() {
+        if end <= start + 1 {
+            continue;
+        }
+
+        let chord = LineSegment {
+            from: points[start],
+            to: points[end],
+        };
+        let (index, distance) = (start + 1..end)
+            .map(|i| (i, chord.distance_to_point(points[i])))
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .unwrap_or((start, 0.0));
+
+        if distance > tolerance {
+            keep[index] = true;
+            stack.push((start, index));
+            stack.push((index, end));
+        }
+    }
+
+    (0..points.len()).filter(|index| keep[*index]).collect()
+}
@@ -0,0 +1,360 @@
+//! Provides ways of splitting the segments of a path into smaller segments of the same kind.
+
+use lyon::{
+    geom::{CubicBezierSegment, LineSegment, QuadraticBezierSegment, Segment},
+    math::Point,
+    path::{Event, path::Builder},
+};
+
+impl super::Path {
+    /// Splits every Bézier curve into `max_segments` smaller curves of the same degree.
+    ///
+    /// Unlike flattening, the result still consists of curves: each curve is cut at evenly
+    /// spaced parameter values with de Casteljau's algorithm, so the pieces trace exactly the
+    /// same shape. Shorter curves can improve the quality of per-segment operations such as
+    /// offsetting. Lines are left as they are. Per-vertex scalars are interpolated onto the
+    /// new vertices.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_segments` - The number of pieces each curve is split into. Values below `2`
+    ///   leave the path unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lyon::geom::{CubicBezierSegment, point};
+    /// use path_offset::path::{Path, event::PathEvent};
+    /// use std::str::FromStr;
+    ///
+    /// let path = Path::from_str("M0,0 C10,20 30,20 40,0").unwrap();
+    /// let subdivided = path.subdivide_curves(4);
+    ///
+    /// let original = CubicBezierSegment {
+    ///     from: point(0.0, 0.0),
+    ///     ctrl1: point(10.0, 20.0),
+    ///     ctrl2: point(30.0, 20.0),
+    ///     to: point(40.0, 0.0),
+    /// };
+    /// let pieces: Vec<_> = subdivided
+    ///     .events()
+    ///     .filter_map(|event| match event {
+    ///         PathEvent::Cubic { from, ctrl1, ctrl2, to } => Some(CubicBezierSegment {
+    ///             from: point(from.0 as f32, from.1 as f32),
+    ///             ctrl1: point(ctrl1.0 as f32, ctrl1.1 as f32),
+    ///             ctrl2: point(ctrl2.0 as f32, ctrl2.1 as f32),
+    ///             to: point(to.0 as f32, to.1 as f32),
+    ///         }),
+    ///         _ => None,
+    ///     })
+    ///     .collect();
+    /// assert_eq!(pieces.len(), 4);
+    ///
+    /// // Each piece traces its quarter of the original curve.
+    /// for (i, piece) in pieces.iter().enumerate() {
+    ///     for step in 0..=10 {
+    ///         let t = step as f32 / 10.0;
+    ///         let expected = original.sample((i as f32 + t) / 4.0);
+    ///         assert!((piece.sample(t) - expected).length() < 1e-3);
+    ///     }
+    /// }
+    /// ```
+    pub fn subdivide_curves(&self, max_segments: usize) -> super::Path {
+        if max_segments < 2 {
+            return self.clone();
+        }
+
+        let mut builder = lyon::path::Path::builder();
+        let mut scalars = self.scalars.as_ref().map(|_| Vec::new());
+        // The index of the vertex the next event ends at.
+        let mut vertex = 0;
+        let pieces = (1..=max_segments).map(|i| i as f32 / max_segments as f32);
+
+        for event in self.inner.iter() {
+            let mut push_scalar = |vertex: f64| {
+                if let Some(scalars) = &mut scalars {
+                    scalars.extend(self.scalar_at(vertex));
+                }
+            };
+
+            match event {
+                Event::Begin { at } => {
+                    builder.begin(at);
+                    push_scalar(vertex as f64);
+                }
+                Event::Line { to, .. } => {
+                    builder.line_to(to);
+                    push_scalar(vertex as f64);
+                }
+                Event::Quadratic { from, ctrl, to } => {
+                    let curve = QuadraticBezierSegment { from, ctrl, to };
+                    let mut start = 0.0;
+                    for end in pieces.clone() {
+                        let piece = curve.split_range(start..end);
+                        builder.quadratic_bezier_to(piece.ctrl, piece.to);
+                        push_scalar(vertex as f64 - 1.0 + end as f64);
+                        start = end;
+                    }
+                }
+                Event::Cubic {
+                    from,
+                    ctrl1,
+                    ctrl2,
+                    to,
+                } => {
+                    let curve = CubicBezierSegment {
+                        from,
+                        ctrl1,
+                        ctrl2,
+                        to,
+                    };
+                    let mut start = 0.0;
+                    for end in pieces.clone() {
+                        let piece = curve.split_range(start..end);
+                        builder.cubic_bezier_to(piece.ctrl1, piece.ctrl2, piece.to);
+                        push_scalar(vertex as f64 - 1.0 + end as f64);
+                        start = end;
+                    }
+                }
+                Event::End { close, .. } => {
+                    builder.end(close);
+                    continue;
+                }
+            }
+            vertex += 1;
+        }
+
+        super::Path {
+            inner: builder.build(),
+            scalars,
+        }
+    }
+
+    /// Splits the first subpath at the arc length `s` from its start.
+    ///
+    /// The segment containing the cut is split exactly with de Casteljau's algorithm, so
+    /// lines stay lines and curves stay curves of the same degree, and the two halves meet
+    /// at the same point. The first path holds the subpath up to the cut. The second holds
+    /// the rest of it, followed by the other subpaths of the path unchanged, so that
+    /// appending the two gives back the whole path. Cutting a closed subpath opens it: its
+    /// closing segment belongs to the second half. Per-vertex scalars are interpolated
+    /// onto the cut.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The arc length along the first subpath at which to cut. Values up to `0`
+    ///   put the whole path in the second half, values past the length of the subpath put
+    ///   all of it in the first.
+    /// * `tolerance` - The precision with which curves are measured, as in
+    ///   [`Path::length`](super::Path::length).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::{Path, event::PathEvent};
+    /// use std::str::FromStr;
+    ///
+    /// let path = Path::from_str("M0,0 L10,0 C15,0 20,5 20,10 M30,0 L40,0").unwrap();
+    /// let (first, second) = path.split_at_length(5.0, 0.001);
+    /// assert_eq!(first.to_string(), "M0,0L5,0");
+    /// assert_eq!(second.to_string(), "M5,0L10,0C15,0 20,5 20,10M30,0L40,0");
+    ///
+    /// // A cut inside the curve splits it into two curves that meet exactly.
+    /// let (first, second) = path.split_at_length(15.0, 0.001);
+    /// assert!((first.length(0.001) - 15.0).abs() < 0.01);
+    /// let Some(PathEvent::End { last, .. }) = first.events().last() else { panic!() };
+    /// let Some(PathEvent::Begin { at }) = second.events().next() else { panic!() };
+    /// assert_eq!(last, at);
+    ///
+    /// // Cutting a closed square opens it.
+    /// let square = Path::from_str("M0,0 L10,0 L10,10 L0,10 Z").unwrap();
+    /// let (first, second) = square.split_at_length(25.0, 0.001);
+    /// assert_eq!(first.to_string(), "M0,0L10,0L10,10L5,10");
+    /// assert_eq!(second.to_string(), "M5,10L0,10L0,0");
+    /// ```
+    pub fn split_at_length(&self, s: f64, tolerance: f64) -> (super::Path, super::Path) {
+        let Some(subpath) = self.subpath(0) else {
+            return (std::iter::empty().collect(), self.clone());
+        };
+        let rest = || self.iter().skip(1);
+        if s <= 0.0 {
+            return (std::iter::empty().collect(), self.clone());
+        }
+        if s >= subpath.length(tolerance) {
+            return (subpath, rest().collect());
+        }
+
+        let tolerance = tolerance as f32;
+        let (mut before, mut after) = (lyon::path::Path::builder(), lyon::path::Path::builder());
+        let mut before_scalars = subpath.scalars.as_ref().map(|_| Vec::new());
+        let mut after_scalars = before_scalars.clone();
+        let scalars = subpath.scalars.as_deref();
+        // The scalar at a fractional parameter along the segment ending at vertex `end`.
+        let scalar_at = |start: usize, end: usize, t: f32| {
+            scalars.map(|scalars| scalars[start] + (scalars[end] - scalars[start]) * t as f64)
+        };
+
+        let mut travelled = 0.0;
+        let mut cut = false;
+        let mut vertex = 0;
+        for event in subpath.inner.iter() {
+            let piece = match event {
+                Event::Begin { at } => {
+                    before.begin(at);
+                    before_scalars
+                        .iter_mut()
+                        .for_each(|v| v.extend(scalar_at(0, 0, 0.0)));
+                    continue;
+                }
+                Event::Line { from, to } => Piece::Line(LineSegment { from, to }),
+                Event::Quadratic { from, ctrl, to } => {
+                    Piece::Quadratic(QuadraticBezierSegment { from, ctrl, to })
+                }
+                Event::Cubic {
+                    from,
+                    ctrl1,
+                    ctrl2,
+                    to,
+                } => Piece::Cubic(CubicBezierSegment {
+                    from,
+                    ctrl1,
+                    ctrl2,
+                    to,
+                }),
+                Event::End { last, first, close } => {
+                    if !close || last == first {
+                        continue;
+                    }
+                    Piece::Line(LineSegment {
+                        from: last,
+                        to: first,
+                    })
+                }
+            };
+            // The closing segment ends back at the first vertex.
+            let end = if matches!(event, Event::End { .. }) {
+                0
+            } else {
+                vertex + 1
+            };
+            let length = piece.length(tolerance) as f64;
+
+            if cut {
+                piece.add_to(&mut after);
+                after_scalars
+                    .iter_mut()
+                    .for_each(|v| v.extend(scalar_at(end, end, 0.0)));
+            } else if travelled + length > s {
+                let t = piece.parameter_at_length((s - travelled) as f32, tolerance);
+                let (head, tail) = piece.split(t);
+                let scalar = scalar_at(vertex, end, t);
+
+                head.add_to(&mut before);
+                before.end(false);
+                before_scalars.iter_mut().for_each(|v| v.extend(scalar));
+                after.begin(tail.from());
+                tail.add_to(&mut after);
+                after_scalars.iter_mut().for_each(|v| {
+                    v.extend(scalar);
+                    v.extend(scalar_at(end, end, 0.0));
+                });
+                cut = true;
+            } else {
+                piece.add_to(&mut before);
+                before_scalars
+                    .iter_mut()
+                    .for_each(|v| v.extend(scalar_at(end, end, 0.0)));
+            }
+
+            travelled += length;
+            vertex += 1;
+        }
+        after.end(false);
+
+        let first = super::Path {
+            inner: before.build(),
+            scalars: before_scalars,
+        };
+        let second = super::Path {
+            inner: after.build(),
+            scalars: after_scalars,
+        };
+        (first, [second].into_iter().chain(rest()).collect())
+    }
+}
+
+/// A single segment of a path, of any kind.
+#[derive(Clone, Copy)]
+enum Piece {
+    Line(LineSegment<f32>),
+    Quadratic(QuadraticBezierSegment<f32>),
+    Cubic(CubicBezierSegment<f32>),
+}
+
+impl Piece {
+    fn from(&self) -> Point {
+        match self {
+            Piece::Line(segment) => segment.from,
+            Piece::Quadratic(segment) => segment.from,
+            Piece::Cubic(segment) => segment.from,
+        }
+    }
+
+    fn length(&self, tolerance: f32) -> f32 {
+        match self {
+            Piece::Line(segment) => segment.length(),
+            Piece::Quadratic(segment) => segment.approximate_length(tolerance),
+            Piece::Cubic(segment) => segment.approximate_length(tolerance),
+        }
+    }
+
+    fn split(&self, t: f32) -> (Piece, Piece) {
+        match self {
+            Piece::Line(segment) => {
+                let (a, b) = segment.split(t);
+                (Piece::Line(a), Piece::Line(b))
+            }
+            Piece::Quadratic(segment) => {
+                let (a, b) = segment.split(t);
+                (Piece::Quadratic(a), Piece::Quadratic(b))
+            }
+            Piece::Cubic(segment) => {
+                let (a, b) = segment.split(t);
+                (Piece::Cubic(a), Piece::Cubic(b))
+            }
+        }
+    }
+
+    /// Finds the parameter at which the segment reaches the arc length `length`.
+    fn parameter_at_length(&self, length: f32, tolerance: f32) -> f32 {
+        if let Piece::Line(segment) = self {
+            return length / segment.length();
+        }
+
+        // Arc length grows monotonically with the parameter, so it can be bisected.
+        let (mut low, mut high) = (0.0, 1.0);
+        for _ in 0..24 {
+            let t = (low + high) / 2.0;
+            if self.split(t).0.length(tolerance) < length {
+                low = t;
+            } else {
+                high = t;
+            }
+        }
+        (low + high) / 2.0
+    }
+
+    fn add_to(&self, builder: &mut Builder) {
+        match self {
+            Piece::Line(segment) => {
+                builder.line_to(segment.to);
+            }
+            Piece::Quadratic(segment) => {
+                builder.quadratic_bezier_to(segment.ctrl, segment.to);
+            }
+            Piece::Cubic(segment) => {
+                builder.cubic_bezier_to(segment.ctrl1, segment.ctrl2, segment.to);
+            }
+        }
+    }
+}
@@ -33,7 +33,9 @@
 //! assert_eq!(subpath_count, 2);
 //! ```
 
-use lyon::path::{Event, Iter as PathIter};
+use std::ops::Range;
+
+use lyon::path::{Event, PathEvent};
 
 /// An iterator that decomposes a path containing multiple shapes into individual subpaths.
 ///
@@ -42,40 +44,174 @@ use lyon::path::{Event, Iter as PathIter};
 /// from a continuous stream of path events.
 ///
 /// It is typically not used directly, but rather through the `for` loop syntax on a `&Path`.
+///
+/// Creating the iterator walks the event stream once to record where each subpath lies.
+/// Subpaths are then built only when they are yielded, from either end: `nth`, `next_back`,
+/// and [`Path::subpath`](super::Path::subpath) skip straight to the subpath they need
+/// without building the ones before it.
+///
+/// Empty subpaths, made of a lone moveto without any segments, are skipped: they have no
+/// length or area, and offsetting them is meaningless.
+///
+/// # Example
+///
+/// ```
+/// use path_offset::path::Path;
+/// use std::str::FromStr;
+///
+/// let path = Path::from_str("M0,0 M10,0 L20,0 M5,5 Z").unwrap();
+/// let subpaths: Vec<String> = path.iter().map(|subpath| subpath.to_string()).collect();
+///
+/// assert_eq!(subpaths, ["M10,0L20,0"]);
+/// ```
+///
+/// A subpath that is not terminated by an `End` event, before the next `Begin` or at the end
+/// of the stream, is yielded as an open subpath rather than dropped. `lyon`'s builders
+/// always end their subpaths, but splicing raw path data together can leave them
+/// unterminated:
+///
+/// ```
+/// use path_offset::path::Path;
+///
+/// let mut tail = lyon::path::Path::builder();
+/// tail.begin(lyon::math::point(20.0, 0.0));
+/// tail.line_to(lyon::math::point(30.0, 0.0));
+/// tail.end(true);
+/// let tail = tail.build();
+///
+/// // Append a complete subpath while the first one is still in progress.
+/// let mut builder = lyon::path::Path::builder();
+/// builder.begin(lyon::math::point(0.0, 0.0));
+/// builder.line_to(lyon::math::point(10.0, 0.0));
+/// builder.extend_from_paths(&[tail.as_slice()]);
+/// builder.end(false);
+/// let path = Path::from(builder.build());
+///
+/// let subpaths: Vec<Path> = path.iter().collect();
+/// assert_eq!(subpaths.len(), 2);
+/// assert_eq!(subpaths[0].to_string(), "M0,0L10,0");
+/// assert!(!subpaths[0].is_closed());
+/// assert_eq!(subpaths[1].to_string(), "M20,0L30,0Z");
+/// assert_eq!(path.subpath_count(), 2);
+/// ```
 pub struct SubpathIter<'a> {
-    /// Holds an iterator over the underlying `lyon` path's event stream.
-    iter: PathIter<'a>,
+    /// The events of the underlying `lyon` path.
+    events: Vec<PathEvent>,
+    /// The per-vertex scalars of the path being iterated, if it has any.
+    scalars: Option<&'a [f64]>,
+    /// The location of every non-empty subpath in the event stream.
+    subpaths: Vec<SubpathRange>,
+    /// The indices of the subpaths not yielded yet, from either end.
+    remaining: Range<usize>,
+}
+
+/// The location of a subpath in a path's event stream.
+struct SubpathRange {
+    /// The `Begin` event of the subpath and its segment events.
+    events: Range<usize>,
+    /// The vertices of the subpath, as indices into the per-vertex scalars.
+    vertices: Range<usize>,
+    /// Whether the subpath is closed.
+    close: bool,
 }
 
 impl<'a> Iterator for SubpathIter<'a> {
     // Each iteration yields a complete `Path` object representing one subpath.
     type Item = super::Path;
 
-    /// Implements the core logic of the iterator.
-    ///
-    /// Each call attempts to build and return the next complete subpath from the
-    /// underlying event stream.
+    /// Builds and returns the next subpath from the front.
     fn next(&mut self) -> Option<Self::Item> {
-        // 1. Find the next `Begin` event to start a new subpath builder.
-        let mut builder;
-        if let Some(event) = self.iter.find(|e| matches!(e, Event::Begin { .. })) {
-            if let Event::Begin { at } = event {
-                // Found a start point, initialize the builder.
-                let mut b = lyon::path::Path::builder();
-                b.begin(at);
-                builder = b;
-            } else {
-                // This is theoretically unreachable because `find` ensures it's a Begin event.
-                return None;
+        let index = self.remaining.next()?;
+        Some(self.build(index))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.remaining.size_hint()
+    }
+
+    /// Skips `n` subpaths without building them.
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let index = self.remaining.nth(n)?;
+        Some(self.build(index))
+    }
+}
+
+impl DoubleEndedIterator for SubpathIter<'_> {
+    /// Builds and returns the next subpath from the back.
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = self.remaining.next_back()?;
+        Some(self.build(index))
+    }
+
+    /// Skips `n` subpaths from the back without building them.
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let index = self.remaining.nth_back(n)?;
+        Some(self.build(index))
+    }
+}
+
+impl ExactSizeIterator for SubpathIter<'_> {}
+
+impl<'a> SubpathIter<'a> {
+    /// Creates an iterator over the subpaths of a path, recording where each one lies.
+    fn new(path: &'a super::Path) -> Self {
+        let events: Vec<PathEvent> = path.inner.iter().collect();
+        let mut subpaths = Vec::new();
+        // The `Begin` event and first vertex of the subpath in progress.
+        let mut current: Option<(usize, usize)> = None;
+        let mut vertex = 0;
+
+        let mut finish = |current: Option<(usize, usize)>, end: usize, vertex, close| {
+            // Empty subpaths, made of a lone `Begin`, are skipped.
+            if let Some((begin, first_vertex)) = current
+                && end - begin > 1
+            {
+                subpaths.push(SubpathRange {
+                    events: begin..end,
+                    vertices: first_vertex..vertex,
+                    close,
+                });
             }
-        } else {
-            // No more `Begin` events are found in the stream, so iteration is complete.
-            return None;
-        }
+        };
 
-        // 2. With an active builder, consume events until the corresponding `End` event is found.
-        for event in &mut self.iter {
+        for (index, event) in events.iter().enumerate() {
             match event {
+                Event::Begin { .. } => {
+                    // If another `Begin` is encountered before an `End`, the previous
+                    // subpath was not properly terminated. It is kept as an open subpath.
+                    finish(current, index, vertex, false);
+                    current = Some((index, vertex));
+                    vertex += 1;
+                }
+                Event::End { close, .. } => {
+                    // An `End` event signifies a complete subpath.
+                    finish(current.take(), index, vertex, *close);
+                }
+                _ => vertex += 1,
+            }
+        }
+        // The last subpath may not have a corresponding `End` event either.
+        finish(current, events.len(), vertex, false);
+
+        let remaining = 0..subpaths.len();
+        SubpathIter {
+            events,
+            scalars: path.scalars.as_deref(),
+            subpaths,
+            remaining,
+        }
+    }
+
+    /// Builds the subpath at `index` and wraps it, with its scalars, in our own `Path` type.
+    fn build(&self, index: usize) -> super::Path {
+        let subpath = &self.subpaths[index];
+        let mut builder = lyon::path::Path::builder();
+
+        for event in &self.events[subpath.events.clone()] {
+            match *event {
+                Event::Begin { at } => {
+                    builder.begin(at);
+                }
                 Event::Line { to, .. } => {
                     builder.line_to(to);
                 }
@@ -87,31 +223,19 @@ impl<'a> Iterator for SubpathIter<'a> {
                 } => {
                     builder.cubic_bezier_to(ctrl1, ctrl2, to);
                 }
-                Event::End { close, .. } => {
-                    // An `End` event signifies a complete subpath.
-                    if close {
-                        builder.close();
-                    }
-                    // Build the lyon::path::Path, wrap it in our own Path type, and return it.
-                    // This concludes the current call to next().
-                    return Some(super::Path {
-                        inner: builder.build(),
-                    });
-                }
-                Event::Begin { .. } => {
-                    // If another `Begin` is encountered before an `End`, the previous
-                    // subpath was not properly terminated. In an iterator context,
-                    // the simplest approach is to stop here and let the next call to `next()`
-                    // process this new `Begin` event. This means the unclosed path is discarded.
-                    break;
-                }
+                Event::End { .. } => {}
             }
         }
+        // Open subpaths must be ended explicitly too, otherwise the builder is left
+        // mid-subpath.
+        builder.end(subpath.close);
 
-        // If the loop finishes without returning, it means the iterator was exhausted
-        // but the last subpath did not have a corresponding `End` event.
-        // This incomplete subpath is ignored, and we return None.
-        None
+        super::Path {
+            inner: builder.build(),
+            scalars: self
+                .scalars
+                .map(|scalars| scalars[subpath.vertices.clone()].to_vec()),
+        }
     }
 }
 
@@ -125,8 +249,33 @@ impl<'a> IntoIterator for &'a super::Path {
 
     /// Defines how to create a [`SubpathIter`] from a `&Path`.
     fn into_iter(self) -> Self::IntoIter {
-        SubpathIter {
-            iter: self.inner.iter(),
+        SubpathIter::new(self)
+    }
+}
+
+/// Reassembles a `Path` from a sequence of subpaths.
+///
+/// This is the inverse of iterating over a `&Path`: every event of every item is
+/// appended in order, so each item's subpaths (and their closedness) are preserved.
+/// It makes filtering subpaths a matter of `path.iter().filter(...).collect()`.
+/// Per-vertex scalars are kept if every item carries them.
+impl FromIterator<super::Path> for super::Path {
+    fn from_iter<I: IntoIterator<Item = super::Path>>(iter: I) -> Self {
+        let subpaths: Vec<super::Path> = iter.into_iter().collect();
+        let slices: Vec<_> = subpaths.iter().map(|path| path.inner.as_slice()).collect();
+
+        let mut builder = lyon::path::Path::builder();
+        builder.extend_from_paths(&slices);
+
+        let scalars = subpaths
+            .iter()
+            .map(|path| path.scalars.as_deref())
+            .collect::<Option<Vec<_>>>()
+            .map(|scalars| scalars.concat());
+
+        super::Path {
+            inner: builder.build(),
+            scalars,
         }
     }
 }
@@ -0,0 +1,525 @@
+//! Provides SVG path data serializations besides the full-precision absolute one of `Display`,
+//! a minimal SVG document wrapper for viewing paths, and a lenient parser for path data
+//! exported by design tools.
+
+use std::str::FromStr;
+
+use lyon::{
+    geom::{CubicBezierSegment, QuadraticBezierSegment},
+    math::Point,
+    path::Event,
+};
+
+use crate::error::{PathError, Result};
+
+/// A point with `f64` coordinates, used to fit arcs precisely.
+type Point64 = lyon::geom::Point<f64>;
+
+impl super::Path {
+    /// Parses SVG path data, accepting forms that design tools export but `from_str` rejects.
+    ///
+    /// On top of what `from_str` accepts, which already includes exponential notation (`1e-3`),
+    /// numbers run together where a sign or a second decimal point starts the next one
+    /// (`10-5`, `.5.5`), and surrounding or repeated whitespace and commas, this accepts:
+    /// - Explicit `+` signs, on numbers (`+3`, `1+2`) and on exponents (`1e+3`).
+    /// - Semicolons between path data strings (`M0,0 L1,1; M2,2 L3,3`), so several `d`
+    ///   attributes joined together parse as one path with a subpath for each.
+    ///
+    /// Any other deviation from the SVG path grammar is an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PathError::Parse`] if the data is invalid
+    /// even with the deviations above. Its position refers to `s` as given.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// let data = "  M+1e+1,0 L20-5;\nM0,0 L.5.5  ";
+    /// assert!(Path::from_str(data).is_err());
+    ///
+    /// let path = Path::from_svg_lenient(data).unwrap();
+    /// assert_eq!(path.to_string(), "M10,0L20,-5M0,0L0.5,0.5");
+    /// assert_eq!(path.subpath_count(), 2);
+    ///
+    /// assert!(Path::from_svg_lenient("M0,0 L1,#").is_err());
+    /// ```
+    pub fn from_svg_lenient(s: &str) -> Result<Self> {
+        // Every replacement keeps the length of the data, so that error positions still
+        // refer to the original.
+        let mut normalized = String::with_capacity(s.len());
+        let mut previous = [' ', ' '];
+        for c in s.chars() {
+            let in_exponent = matches!(previous[1], 'e' | 'E')
+                && (previous[0].is_ascii_digit() || previous[0] == '.');
+            normalized.push(match c {
+                // `1e+3` is the same number as `1e03`.
+                '+' if in_exponent => '0',
+                '+' | ';' => ' ',
+                c => c,
+            });
+            previous = [previous[1], c];
+        }
+
+        super::Path::from_str(&normalized).map_err(|error| match error {
+            PathError::Parse { source, .. } => PathError::parse(s, source),
+            error => error,
+        })
+    }
+
+    /// Serializes the path as SVG path data with coordinates rounded to `decimals` places.
+    ///
+    /// The output has the same absolute commands as `Display`, but every coordinate is
+    /// rounded, with trailing zeros dropped. Tiny floating point errors, as in
+    /// `M10.000001,9.999998`, disappear, which keeps generated SVG compact and makes
+    /// snapshot tests deterministic.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// let path = Path::from_str("M10.000001,9.999998 L20.25,-0.0001 Q1.23456,2 3,4 Z").unwrap();
+    /// assert_eq!(path.to_svg_with_precision(2), "M10,10L20.25,0Q1.23,2 3,4Z");
+    /// assert_eq!(path.to_svg_with_precision(0), "M10,10L20,0Q1,2 3,4Z");
+    /// ```
+    pub fn to_svg_with_precision(&self, decimals: usize) -> String {
+        let number = |value: f32| {
+            let mut number = format!("{:.*}", decimals, value);
+            if number.contains('.') {
+                number.truncate(number.trim_end_matches('0').trim_end_matches('.').len());
+            }
+            // Rounding a small negative number leaves a negative zero.
+            if number == "-0" {
+                number.remove(0);
+            }
+            number
+        };
+        let point = |point: Point| format!("{},{}", number(point.x), number(point.y));
+
+        let mut output = String::new();
+        for event in self.inner.iter() {
+            match event {
+                Event::Begin { at } => output += &format!("M{}", point(at)),
+                Event::Line { to, .. } => output += &format!("L{}", point(to)),
+                Event::Quadratic { ctrl, to, .. } => {
+                    output += &format!("Q{} {}", point(ctrl), point(to));
+                }
+                Event::Cubic {
+                    ctrl1, ctrl2, to, ..
+                } => output += &format!("C{} {} {}", point(ctrl1), point(ctrl2), point(to)),
+                Event::End { close, .. } => {
+                    if close {
+                        output.push('Z');
+                    }
+                }
+            }
+        }
+
+        output
+    }
+
+    /// Serializes the path as absolute SVG path data, writing circular arcs as `A` commands.
+    ///
+    /// SVG arcs do not survive parsing: they are approximated with Bézier curves, and
+    /// `Display` writes those curves out. This method recognizes curves that follow a
+    /// circular arc, as produced from arcs by the parser or by most CAD exports, and merges
+    /// consecutive ones around the same circle into `A` commands of at most a half turn each.
+    /// A curve qualifies if it leaves and enters a circle along its tangents, and stays
+    /// within half a percent of its radius in between. Elliptical
+    /// arcs are not recognized and are written as curves, like every other segment.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// // The parser turns this circle into eight quadratic curves.
+    /// let circle = Path::from_str("M10,0 A10,10 0 1 1 -10,0 A10,10 0 1 1 10,0 Z").unwrap();
+    /// assert_eq!(circle.to_string().matches('Q').count(), 8);
+    ///
+    /// let svg = circle.to_svg_preserve_arcs();
+    /// assert_eq!(svg.matches('A').count(), 2);
+    /// assert!(!svg.contains('Q'));
+    ///
+    /// let reparsed = Path::from_str(&svg).unwrap();
+    /// assert!(reparsed.approx_eq(&circle, 1e-3));
+    /// ```
+    pub fn to_svg_preserve_arcs(&self) -> String {
+        let mut output = String::new();
+        // The arc being merged, as its center, radius, sweep angle, and end point. Merging
+        // stops at a half turn, up to rounding errors.
+        const HALF_TURN: f64 = std::f64::consts::PI + 1e-6;
+        let mut pending: Option<(Point64, f64, f64, Point)> = None;
+
+        fn write_arc(output: &mut String, (_, radius, sweep, to): (Point64, f64, f64, Point)) {
+            let radius = radius as f32;
+            let large_arc = u8::from(sweep.abs() > HALF_TURN);
+            let sweep_flag = u8::from(sweep > 0.0);
+            output.push_str(&format!(
+                "A{radius},{radius} 0 {large_arc} {sweep_flag} {},{}",
+                to.x, to.y
+            ));
+        }
+
+        for event in self.inner.iter() {
+            let arc = match event {
+                Event::Quadratic { from, ctrl, to } => {
+                    circular_arc(&QuadraticBezierSegment { from, ctrl, to }.to_cubic())
+                }
+                Event::Cubic {
+                    from,
+                    ctrl1,
+                    ctrl2,
+                    to,
+                } => circular_arc(&CubicBezierSegment {
+                    from,
+                    ctrl1,
+                    ctrl2,
+                    to,
+                }),
+                _ => None,
+            };
+
+            if let Some((center, radius, sweep)) = arc {
+                let to = match event {
+                    Event::Quadratic { to, .. } | Event::Cubic { to, .. } => to,
+                    _ => unreachable!(),
+                };
+                pending = match pending {
+                    Some((pending_center, pending_radius, pending_sweep, _))
+                        if (pending_center - center).length() <= 1e-3 * radius
+                            && (pending_radius - radius).abs() <= 1e-3 * radius
+                            && pending_sweep.signum() == sweep.signum()
+                            && (pending_sweep + sweep).abs() <= HALF_TURN =>
+                    {
+                        Some((pending_center, pending_radius, pending_sweep + sweep, to))
+                    }
+                    previous => {
+                        if let Some(previous) = previous {
+                            write_arc(&mut output, previous);
+                        }
+                        Some((center, radius, sweep, to))
+                    }
+                };
+                continue;
+            }
+
+            if let Some(previous) = pending.take() {
+                write_arc(&mut output, previous);
+            }
+            match event {
+                Event::Begin { at } => output.push_str(&format!("M{},{}", at.x, at.y)),
+                Event::Line { to, .. } => output.push_str(&format!("L{},{}", to.x, to.y)),
+                Event::Quadratic { ctrl, to, .. } => {
+                    output.push_str(&format!("Q{},{} {},{}", ctrl.x, ctrl.y, to.x, to.y));
+                }
+                Event::Cubic {
+                    ctrl1, ctrl2, to, ..
+                } => output.push_str(&format!(
+                    "C{},{} {},{} {},{}",
+                    ctrl1.x, ctrl1.y, ctrl2.x, ctrl2.y, to.x, to.y
+                )),
+                Event::End { close, .. } => {
+                    if close {
+                        output.push('Z');
+                    }
+                }
+            }
+        }
+
+        output
+    }
+
+    /// Serializes the path as absolute SVG path data, using the `S` and `T` shorthands.
+    ///
+    /// A curve whose first control point mirrors the last control point of the previous
+    /// curve of the same kind, across their shared end point, is written with the smooth
+    /// shorthand, which leaves that control point out: `S` for cubic curves and `T` for
+    /// quadratic ones. Smooth joins are common in font outlines and traced shapes, where this
+    /// saves a third of the numbers. A control point is considered mirrored if it lies within
+    /// `tolerance` of the reflection, so reparsing the output moves it by at most that much.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// let path = Path::from_str("M0,0 C0,5 5,10 10,10 C15,10 20,5 20,0 Q25,-5 30,0 Q35,5 40,0").unwrap();
+    /// let svg = path.to_svg_with_shorthand(1e-3);
+    /// assert_eq!(svg, "M0,0C0,5 5,10 10,10S20,5 20,0Q25,-5 30,0T40,0");
+    ///
+    /// // The shorthand output describes the same geometry.
+    /// let reparsed = Path::from_str(&svg).unwrap();
+    /// assert_eq!(reparsed.to_string(), path.to_string());
+    /// ```
+    pub fn to_svg_with_shorthand(&self, tolerance: f32) -> String {
+        let mut output = String::new();
+        // The last control point of the previous curve, as reparsing the output sees it.
+        let mut previous_cubic: Option<Point> = None;
+        let mut previous_quadratic: Option<Point> = None;
+        let mirrored = |previous: Option<Point>, from: Point, ctrl: Point| {
+            previous.filter(|previous| (from + (from - *previous)).distance_to(ctrl) <= tolerance)
+        };
+
+        for event in self.inner.iter() {
+            let (mut cubic, mut quadratic) = (None, None);
+            match event {
+                Event::Begin { at } => output += &format!("M{},{}", at.x, at.y),
+                Event::Line { to, .. } => output += &format!("L{},{}", to.x, to.y),
+                Event::Quadratic { from, ctrl, to } => {
+                    // The shorthand control point is the exact reflection, which becomes
+                    // the reference for the next curve.
+                    if let Some(previous) = mirrored(previous_quadratic, from, ctrl) {
+                        output += &format!("T{},{}", to.x, to.y);
+                        quadratic = Some(from + (from - previous));
+                    } else {
+                        output += &format!("Q{},{} {},{}", ctrl.x, ctrl.y, to.x, to.y);
+                        quadratic = Some(ctrl);
+                    }
+                }
+                Event::Cubic {
+                    from,
+                    ctrl1,
+                    ctrl2,
+                    to,
+                } => {
+                    if mirrored(previous_cubic, from, ctrl1).is_some() {
+                        output += &format!("S{},{} {},{}", ctrl2.x, ctrl2.y, to.x, to.y);
+                    } else {
+                        output += &format!(
+                            "C{},{} {},{} {},{}",
+                            ctrl1.x, ctrl1.y, ctrl2.x, ctrl2.y, to.x, to.y
+                        );
+                    }
+                    cubic = Some(ctrl2);
+                }
+                Event::End { close, .. } => {
+                    if close {
+                        output.push('Z');
+                    }
+                }
+            }
+            (previous_cubic, previous_quadratic) = (cubic, quadratic);
+        }
+
+        output
+    }
+
+    /// Serializes the path as SVG path data with relative commands.
+    ///
+    /// Every command is written in its lowercase, relative form, with coordinates given as
+    /// offsets from the current point. Each command repeats its letter; see
+    /// [`Path::to_relative_commands_string`](super::Path::to_relative_commands_string) for
+    /// the more compact form.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// let path = Path::from_str("M10,10 L11,10 L12,10 L12,11 Z").unwrap();
+    /// assert_eq!(path.to_relative_string(), "m10,10l1,0l1,0l0,1z");
+    ///
+    /// // Parsing the relative form gives back the same geometry, across several subpaths.
+    /// let path = Path::from_str("M0,0 L10,0 C10,5 5,10 0,10 Z M20.5,3 Q25,0 30,3 L31,-4").unwrap();
+    /// let reparsed = Path::from_str(&path.to_relative_string()).unwrap();
+    /// assert!(reparsed.approx_eq(&path, 1e-4));
+    /// ```
+    pub fn to_relative_string(&self) -> String {
+        self.write_relative(false)
+    }
+
+    /// Serializes the path as SVG path data with relative commands, grouping repeated ones.
+    ///
+    /// Like [`Path::to_relative_string`](super::Path::to_relative_string), but consecutive
+    /// commands of the same type share one command letter (`l1,0 1,0 1,0` instead of
+    /// `l1,0l1,0l1,0`), as common SVG optimizers do. Lines directly after a moveto omit their
+    /// letter too, since they are implied, and separators before negative numbers are
+    /// dropped because the minus sign already separates them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// let path = Path::from_str("M10,10 L11,9 L12,8 L13,7 L13,11 Q14,12 15,11 Z M0,0 L1,1").unwrap();
+    /// let grouped = path.to_relative_commands_string();
+    ///
+    /// assert_eq!(grouped, "m10,10 1-1 1-1 1-1 0,4q1,1 2,0zm-10-10 1,1");
+    /// assert!(grouped.len() < path.to_relative_string().len());
+    ///
+    /// // The grouped output describes the same geometry.
+    /// let reparsed = Path::from_str(&grouped).unwrap();
+    /// assert_eq!(reparsed.to_string(), path.to_string());
+    /// ```
+    pub fn to_relative_commands_string(&self) -> String {
+        self.write_relative(true)
+    }
+
+    /// Writes the relative path data, optionally omitting repeated command letters.
+    fn write_relative(&self, group: bool) -> String {
+        let mut output = String::new();
+        let mut current = Point::zero();
+        let mut previous_command = None;
+
+        let push_number = |output: &mut String, value: f32, separator: char| {
+            let number = value.to_string();
+            let needs_separator = output.ends_with(|c: char| c.is_ascii_digit() || c == '.');
+            if needs_separator && !(group && number.starts_with('-')) {
+                output.push(separator);
+            }
+            output.push_str(&number);
+        };
+
+        for event in self.inner.iter() {
+            let (command, points): (char, Vec<Point>) = match event {
+                Event::Begin { at } => ('m', vec![at]),
+                Event::Line { to, .. } => ('l', vec![to]),
+                Event::Quadratic { ctrl, to, .. } => ('q', vec![ctrl, to]),
+                Event::Cubic {
+                    ctrl1, ctrl2, to, ..
+                } => ('c', vec![ctrl1, ctrl2, to]),
+                Event::End { first, close, .. } => {
+                    if close {
+                        output.push('z');
+                        // Closing returns the current point to the start of the subpath.
+                        current = first;
+                    }
+                    previous_command = None;
+                    continue;
+                }
+            };
+
+            // The implicit continuation of a moveto is a lineto.
+            let implied = match command {
+                'l' => previous_command == Some('m') || previous_command == Some('l'),
+                _ => previous_command == Some(command) && command != 'm',
+            };
+            if !(group && implied) {
+                output.push(command);
+            }
+            for point in &points {
+                push_number(&mut output, point.x - current.x, ' ');
+                push_number(&mut output, point.y - current.y, ',');
+            }
+
+            current = *points.last().unwrap_or(&current);
+            previous_command = Some(command);
+        }
+
+        output
+    }
+}
+
+/// Wraps paths in a minimal SVG document, to view them in a browser or an SVG editor.
+///
+/// The document is `width` by `height` units, with a matching `viewBox` starting at the
+/// origin, and holds one `<path>` element per path, in order, with its full-precision path
+/// data. Every path is painted with the same `fill` and `stroke`, which take any SVG paint
+/// value, such as `"none"`, `"red"`, or `"#3366ff80"`.
+///
+/// # Example
+///
+/// ```
+/// use path_offset::{Backend, offset, path::{Path, to_svg_document}};
+/// use std::str::FromStr;
+///
+/// let square = Path::from_str("M10,10 L30,10 L30,30 L10,30 Z").unwrap();
+/// let grown = offset(&square, 5.0, Backend::CavalierContours).unwrap();
+///
+/// let document = to_svg_document(&[square, grown], 40.0, 40.0, "none", "black");
+/// assert!(document.starts_with(
+///     r#"<svg xmlns="http://www.w3.org/2000/svg" width="40" height="40" viewBox="0 0 40 40">"#
+/// ));
+/// assert!(document.contains(r#"<path d="M10,10L30,10L30,30L10,30Z" fill="none" stroke="black"/>"#));
+/// assert_eq!(document.matches("<path ").count(), 2);
+/// // Writing the document to a file makes it viewable in a browser:
+/// // std::fs::write("offset.svg", document).unwrap();
+/// ```
+pub fn to_svg_document(
+    paths: &[super::Path],
+    width: f64,
+    height: f64,
+    fill: &str,
+    stroke: &str,
+) -> String {
+    let (fill, stroke) = (escape_attribute(fill), escape_attribute(stroke));
+    let mut document = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#
+    );
+    document.push('\n');
+    for path in paths {
+        document.push_str(&format!(
+            r#"  <path d="{path}" fill="{fill}" stroke="{stroke}"/>"#
+        ));
+        document.push('\n');
+    }
+    document.push_str("</svg>\n");
+    document
+}
+
+/// Escapes the characters that cannot appear as such in a double-quoted XML attribute.
+fn escape_attribute(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('"', "&quot;")
+}
+
+/// Returns the circular arc a curve follows, as its center, radius, and signed sweep angle.
+///
+/// Returns `None` unless the normals at both endpoints meet at a point equally far from
+/// them, and the curve stays within half a percent of that distance in between.
+fn circular_arc(curve: &CubicBezierSegment<f32>) -> Option<(Point64, f64, f64)> {
+    let curve = CubicBezierSegment {
+        from: curve.from.cast::<f64>(),
+        ctrl1: curve.ctrl1.cast::<f64>(),
+        ctrl2: curve.ctrl2.cast::<f64>(),
+        to: curve.to.cast::<f64>(),
+    };
+    let (from, to) = (curve.from, curve.to);
+    let start_tangent = curve.ctrl1 - from;
+    let end_tangent = to - curve.ctrl2;
+    if start_tangent.square_length() == 0.0 || end_tangent.square_length() == 0.0 {
+        return None;
+    }
+
+    // The center is where the normals at both endpoints meet.
+    let start_normal = lyon::geom::vector(-start_tangent.y, start_tangent.x);
+    let end_normal = lyon::geom::vector(-end_tangent.y, end_tangent.x);
+    let denominator = start_normal.cross(end_normal);
+    if denominator.abs() <= 1e-9 * start_normal.length() * end_normal.length() {
+        return None;
+    }
+    let center = from + start_normal * ((to - from).cross(end_normal) / denominator);
+
+    let radius = (from - center).length();
+    let on_circle = |point: Point64| ((point - center).length() - radius).abs() <= 5e-3 * radius;
+    if !on_circle(to)
+        || ![0.25, 0.5, 0.75]
+            .iter()
+            .all(|&t| on_circle(curve.sample(t)))
+    {
+        return None;
+    }
+
+    // The angle between the radii is ambiguous by a full turn; the start tangent tells
+    // which way around the curve goes.
+    let (start, end) = (from - center, to - center);
+    let mut sweep = start.cross(end).atan2(start.dot(end));
+    let direction = start.cross(start_tangent).signum();
+    if sweep.signum() != direction {
+        sweep += direction * std::f64::consts::TAU;
+    }
+
+    Some((center, radius, sweep))
+}
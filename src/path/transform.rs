@@ -0,0 +1,177 @@
+//! Provides geometric transformations of paths.
+//!
+//! Every transformation is applied to all endpoints and control points, so curves are
+//! transformed exactly rather than approximated. Arbitrary affine transformations are
+//! described by [`Transform`] and applied with [`Path::transform`](super::Path::transform).
+
+use lyon::geom::{Angle, point, traits::Transformation, vector};
+
+use super::point::Point;
+
+/// A 2D affine transformation, such as a translation, a scale, or a rotation.
+///
+/// Transformations are built from the convenience constructors and chained with
+/// [`Transform::then`]. They are computed in `f64` and only rounded to the `f32`
+/// precision of paths when applied.
+///
+/// # Example
+///
+/// ```
+/// use path_offset::path::{Path, transform::Transform};
+/// use std::str::FromStr;
+///
+/// let path = Path::from_str("M0,0 L10,0 L10,10 Z").unwrap();
+///
+/// // Rotate a quarter turn about the origin, then move it to the right.
+/// let transform = Transform::rotate(std::f64::consts::FRAC_PI_2).then(&Transform::translate(20.0, 0.0));
+/// assert_eq!(path.transform(&transform).to_string(), "M20,0L20,10L10,10Z");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform(lyon::geom::Transform<f64>);
+
+impl Transform {
+    /// Creates the identity transformation, which leaves paths unchanged.
+    pub fn identity() -> Self {
+        Transform(lyon::geom::Transform::identity())
+    }
+
+    /// Creates a translation by `dx` along the x axis and `dy` along the y axis.
+    pub fn translate(dx: f64, dy: f64) -> Self {
+        Transform(lyon::geom::Transform::translation(dx, dy))
+    }
+
+    /// Creates a scale about the origin by `sx` along the x axis and `sy` along the y axis.
+    ///
+    /// A negative factor mirrors the path, which flips the winding of its subpaths.
+    pub fn scale(sx: f64, sy: f64) -> Self {
+        Transform(lyon::geom::Transform::scale(sx, sy))
+    }
+
+    /// Creates a rotation about the origin by `radians`.
+    ///
+    /// Positive angles rotate from the x axis towards the y axis, which is clockwise on
+    /// screen when the y axis points down, as in SVG.
+    pub fn rotate(radians: f64) -> Self {
+        Transform(lyon::geom::Transform::rotation(Angle::radians(radians)))
+    }
+
+    /// Returns the transformation that applies `self` first, then `other`.
+    pub fn then(&self, other: &Transform) -> Self {
+        Transform(self.0.then(&other.0))
+    }
+
+    /// Returns the transformation that undoes this one, or `None` if it collapses paths
+    /// onto a line or a point, for example a scale by zero.
+    pub fn inverse(&self) -> Option<Self> {
+        self.0.inverse().map(Transform)
+    }
+}
+
+impl super::Path {
+    /// Reflects the path across the line through `axis_point` with direction `axis_dir`.
+    ///
+    /// Every endpoint and control point is mirrored, which is useful for generating
+    /// symmetric parts from one half.
+    ///
+    /// Mirroring flips the winding of every subpath: a counter-clockwise contour becomes
+    /// clockwise and its signed area changes sign. Since the offset direction depends on
+    /// winding, a distance that grows the original path shrinks the mirrored one. Negate
+    /// the distance (or reverse the mirrored path) to keep offsetting in the same direction.
+    ///
+    /// # Arguments
+    ///
+    /// * `axis_point` - Any point on the mirror axis.
+    /// * `axis_dir` - The direction of the mirror axis. It does not need to be normalized,
+    ///   but must not be zero; a zero direction leaves the path unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::{Path, point::Point};
+    /// use std::str::FromStr;
+    ///
+    /// let triangle = Path::from_str("M1,1 L3,1 L2,4 Z").unwrap();
+    /// let mirrored = triangle.mirror(Point(0.0, 0.0), Point(0.0, 1.0));
+    ///
+    /// assert_eq!(mirrored.to_string(), "M-1,1L-3,1L-2,4Z");
+    /// ```
+    pub fn mirror(&self, axis_point: Point, axis_dir: Point) -> super::Path {
+        let length = axis_dir.0.hypot(axis_dir.1);
+        if length == 0.0 {
+            return self.clone();
+        }
+        let (dx, dy) = (axis_dir.0 / length, axis_dir.1 / length);
+
+        // Reflection matrix across a line through the origin with unit direction (dx, dy).
+        let a = dx * dx - dy * dy;
+        let b = 2.0 * dx * dy;
+
+        // Translate so the axis passes through the origin, reflect, and translate back.
+        let (px, py) = (axis_point.0, axis_point.1);
+        let tx = px - (a * px + b * py);
+        let ty = py - (b * px - a * py);
+
+        let reflection = lyon::math::Transform::new(
+            a as f32, b as f32, b as f32, -a as f32, tx as f32, ty as f32,
+        );
+
+        super::Path {
+            inner: self.inner.clone().transformed(&reflection),
+            scalars: self.scalars.clone(),
+        }
+    }
+
+    /// Applies an affine transformation to every endpoint and control point of the path.
+    ///
+    /// Each point is transformed in `f64` and only the result is rounded to the `f32`
+    /// precision of the path. Per-vertex scalars are kept as they are.
+    ///
+    /// # Arguments
+    ///
+    /// * `m` - The transformation to apply.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::{Path, transform::Transform};
+    /// use std::str::FromStr;
+    ///
+    /// let path = Path::from_str("M1.5,2 L10,0 Q12,4 10,8 Z").unwrap();
+    /// let translation = Transform::translate(123.25, -45.5);
+    ///
+    /// let moved = path.transform(&translation);
+    /// assert_eq!(moved.to_string(), "M124.75,-43.5L133.25,-45.5Q135.25,-41.5 133.25,-37.5Z");
+    ///
+    /// let back = moved.transform(&translation.inverse().unwrap());
+    /// assert_eq!(back.to_string(), path.to_string());
+    ///
+    /// // Points are transformed in `f64` and rounded once: the exact rotation of (3, 7) by
+    /// // 0.7 radians ends at y = 7.28654800..., which rounding the rotation itself to `f32`
+    /// // would turn into 7.2865486.
+    /// let segment = Path::from_str("M3,7 L0,0").unwrap();
+    /// let rotated = segment.transform(&Transform::rotate(0.7));
+    /// assert_eq!(rotated.to_string(), "M-2.2149973,7.286548L0,0");
+    /// ```
+    pub fn transform(&self, m: &Transform) -> super::Path {
+        super::Path {
+            inner: self.inner.clone().transformed(&InF64(&m.0)),
+            scalars: self.scalars.clone(),
+        }
+    }
+}
+
+/// Applies a transformation to the `f32` points of a `lyon` path in `f64`, rounding only
+/// the results.
+struct InF64<'a>(&'a lyon::geom::Transform<f64>);
+
+impl Transformation<f32> for InF64<'_> {
+    fn transform_point(&self, p: lyon::math::Point) -> lyon::math::Point {
+        let p = self.0.transform_point(point(p.x as f64, p.y as f64));
+        point(p.x as f32, p.y as f32)
+    }
+
+    fn transform_vector(&self, v: lyon::math::Vector) -> lyon::math::Vector {
+        let v = self.0.transform_vector(vector(v.x as f64, v.y as f64));
+        vector(v.x as f32, v.y as f32)
+    }
+}
@@ -0,0 +1,79 @@
+//! Provides precondition checks for paths that are about to be offset.
+
+use lyon::path::Event;
+
+use crate::error::{PathError, Result};
+
+impl super::Path {
+    /// Checks that every subpath of the path can be offset as a closed shape.
+    ///
+    /// The `flo_curves` backend treats every subpath as a closed loop and silently joins the
+    /// end of an open subpath back to its start with a straight line. That is harmless when
+    /// the subpath already ends where it starts, but otherwise the offset follows a closing
+    /// edge that is not part of the input. This check rejects such subpaths up front.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if every subpath is closed or ends at its own start point, and
+    /// [`PathError::Unsupported`] describing the first offending subpath otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::error::PathError;
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// let closed = Path::from_str("M0,0 L10,0 L10,10 Z M20,0 L30,0 L30,10 L20,0").unwrap();
+    /// assert!(closed.validate_for_offset().is_ok());
+    ///
+    /// let open = Path::from_str("M0,0 L10,0 L10,10 Z M20,0 L30,0 L30,10").unwrap();
+    /// let Err(PathError::Unsupported(message)) = open.validate_for_offset() else {
+    ///     panic!("an open subpath must be rejected");
+    /// };
+    /// assert!(message.contains("subpath 1 is open"));
+    /// ```
+    pub fn validate_for_offset(&self) -> Result<()> {
+        for (index, subpath) in self.iter().enumerate() {
+            for event in subpath.inner.iter() {
+                if let Event::End {
+                    last,
+                    first,
+                    close: false,
+                } = event
+                    && (last - first).length() > 1e-6
+                {
+                    return Err(PathError::Unsupported(format!(
+                        "subpath {index} is open and ends at ({}, {}) instead of its start \
+                         ({}, {}); it would be closed with a straight line before offsetting. \
+                         End it with `Z` if it is meant to be a closed shape, or remove it \
+                         from the path otherwise",
+                        last.x, last.y, first.x, first.y
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the subpaths of the path agree about their winding.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the winding is consistent, and [`PathError::Unsupported`] explaining the
+    /// problem if [`Path::has_mixed_winding`](super::Path::has_mixed_winding) reports mixed
+    /// winding.
+    pub fn validate_winding(&self) -> Result<()> {
+        if self.has_mixed_winding() {
+            return Err(PathError::Unsupported(
+                "the subpaths have mixed winding, so offsetting outward would grow some shells \
+                 and shrink others. Make every shell wind the same way and every hole the \
+                 opposite way, or call `Path::normalize_winding` first"
+                    .to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
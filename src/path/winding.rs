@@ -0,0 +1,184 @@
+//! Provides checks and fixes for the winding of subpaths.
+//!
+//! The offset direction depends on winding: a distance that grows a counter-clockwise
+//! subpath shrinks a clockwise one. For "outward" to mean the same thing for every subpath,
+//! shells must share one winding and holes must have the opposite one.
+
+impl super::Path {
+    /// Checks whether the closed subpaths of the path disagree about their winding.
+    ///
+    /// Each closed subpath's role is derived from how many other subpaths contain it: an
+    /// even count makes it a shell, an odd count a hole. The winding is mixed if two shells
+    /// (or two holes) wind in opposite directions, or a hole winds the same way as a shell.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// // An annulus with a hole of opposite winding is consistent.
+    /// let annulus = Path::from_str("M0,0 L10,0 L10,10 L0,10 Z M3,3 L3,7 L7,7 L7,3 Z").unwrap();
+    /// assert!(!annulus.has_mixed_winding());
+    ///
+    /// // Two side-by-side shells that wind in opposite directions are not.
+    /// let shells = Path::from_str("M0,0 L10,0 L10,10 L0,10 Z M20,0 L20,10 L30,10 L30,0 Z").unwrap();
+    /// assert!(shells.has_mixed_winding());
+    /// assert!(!shells.normalize_winding().has_mixed_winding());
+    /// ```
+    pub fn has_mixed_winding(&self) -> bool {
+        self.winding_mismatches().contains(&true)
+    }
+
+    /// Reverses the subpaths whose winding disagrees with their role.
+    ///
+    /// The largest outermost shell keeps its winding and every other closed subpath is made
+    /// consistent with it (see [`Path::has_mixed_winding`](super::Path::has_mixed_winding)).
+    /// Open subpaths are left as they are.
+    pub fn normalize_winding(&self) -> super::Path {
+        self.iter()
+            .zip(self.winding_mismatches())
+            .map(
+                |(subpath, mismatch)| {
+                    if mismatch { subpath.reverse() } else { subpath }
+                },
+            )
+            .collect()
+    }
+
+    /// Returns the orientation of a closed path, from the sign of its signed area.
+    ///
+    /// Returns `None` if the path is open or encloses no area, like a closed subpath that
+    /// runs back along itself. The path is expected to be a single subpath; for several, the
+    /// orientation is that of their summed area.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::{Path, contour::Orientation};
+    /// use std::str::FromStr;
+    ///
+    /// let square = Path::from_str("M0,0 L10,0 L10,10 L0,10 Z").unwrap();
+    /// assert_eq!(square.orientation(), Some(Orientation::CounterClockwise));
+    /// assert_eq!(square.reverse().orientation(), Some(Orientation::Clockwise));
+    ///
+    /// assert_eq!(Path::from_str("M0,0 L10,0 L10,10").unwrap().orientation(), None);
+    /// assert_eq!(Path::from_str("M0,0 L10,0 Z").unwrap().orientation(), None);
+    /// ```
+    pub fn orientation(&self) -> Option<super::contour::Orientation> {
+        if !self.is_closed() {
+            return None;
+        }
+
+        let area = self.signed_area(0.01);
+        if area > 0.0 {
+            Some(super::contour::Orientation::CounterClockwise)
+        } else if area < 0.0 {
+            Some(super::contour::Orientation::Clockwise)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the path running in the given orientation, reversing it if needed.
+    ///
+    /// Paths without an [`orientation`](super::Path::orientation) are returned unchanged.
+    /// Orienting a path before offsetting it fixes whether a positive distance grows it
+    /// (counter-clockwise) or shrinks it (clockwise).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::offset::{Offset, cavalier_contours::CavalierContours};
+    /// use path_offset::path::{Path, contour::Orientation};
+    /// use std::str::FromStr;
+    ///
+    /// let square = Path::from_str("M0,0 L0,10 L10,10 L10,0 Z").unwrap();
+    /// let oriented = square.ensure_orientation(Orientation::CounterClockwise);
+    /// assert_eq!(oriented.orientation(), Some(Orientation::CounterClockwise));
+    ///
+    /// let grown = CavalierContours::new(1.0).offset_path(&oriented).unwrap();
+    /// assert!(grown.signed_area(0.01).abs() > 100.0);
+    /// ```
+    pub fn ensure_orientation(&self, orientation: super::contour::Orientation) -> super::Path {
+        match self.orientation() {
+            Some(current) if current != orientation => self.reverse(),
+            _ => self.clone(),
+        }
+    }
+
+    /// Reverses the direction of every subpath, keeping the order of the subpaths.
+    ///
+    /// Each subpath visits its points in the opposite order, with the control points of its
+    /// curves swapped accordingly, and stays closed or open. This flips the sign of its
+    /// [`signed_area`](super::Path::signed_area), so that an offset that used to shrink it
+    /// grows it instead. Per-vertex scalars are reversed along with their vertices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use path_offset::path::Path;
+    /// use std::str::FromStr;
+    ///
+    /// let path = Path::from_str("M0,0 L10,0 C10,5 5,10 0,10 Z M20,0 L30,0").unwrap();
+    /// let reversed = path.reverse();
+    ///
+    /// assert!((reversed.signed_area(0.01) + path.signed_area(0.01)).abs() < 1e-3);
+    /// assert_eq!(reversed.iter().nth(1).unwrap().to_string(), "M30,0L20,0");
+    /// assert_eq!(reversed.reverse().to_string(), path.to_string());
+    /// ```
+    pub fn reverse(&self) -> super::Path {
+        self.iter()
+            .map(|subpath| {
+                let mut builder = lyon::path::Path::builder();
+                for event in subpath.inner.reversed() {
+                    builder.path_event(event);
+                }
+                super::Path {
+                    inner: builder.build(),
+                    scalars: subpath.scalars.map(|mut scalars| {
+                        scalars.reverse();
+                        scalars
+                    }),
+                }
+            })
+            .collect()
+    }
+
+    /// Returns, for each subpath, whether its winding disagrees with its role.
+    fn winding_mismatches(&self) -> Vec<bool> {
+        let subpaths: Vec<super::Path> = self.iter().collect();
+        let depths = self.containment_depths();
+
+        // The expected winding of each closed subpath with a non-zero area, as the sign of
+        // its area flipped once per containing subpath.
+        let orientations: Vec<Option<(f32, bool)>> = subpaths
+            .iter()
+            .zip(&depths)
+            .map(|(subpath, depth)| {
+                let area = subpath.approximate_signed_area(0.01);
+                if !subpath.is_closed() || area.abs() <= f32::EPSILON {
+                    return None;
+                }
+                Some((area.abs(), (area > 0.0) == (depth % 2 == 0)))
+            })
+            .collect();
+
+        // The largest outermost shell decides which winding is correct.
+        let reference = depths
+            .iter()
+            .zip(&orientations)
+            .filter(|(depth, _)| **depth == 0)
+            .filter_map(|(_, orientation)| *orientation)
+            .max_by(|(area_a, _), (area_b, _)| area_a.total_cmp(area_b))
+            .map(|(_, positive)| positive);
+
+        orientations
+            .iter()
+            .map(|orientation| match (orientation, reference) {
+                (Some((_, positive)), Some(reference)) => *positive != reference,
+                _ => false,
+            })
+            .collect()
+    }
+}